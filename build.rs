@@ -0,0 +1,112 @@
+use std::{
+    env, fs,
+    path::{Path, PathBuf},
+};
+
+/// Files next to a day's solution that the solvers read at runtime.
+const DAY_FILES: [&str; 2] = ["input.txt", "size.txt"];
+
+fn main() {
+    println!("cargo:rerun-if-changed=build.rs");
+
+    let out_dir = env::var("OUT_DIR").unwrap();
+    let mut entries = String::new();
+
+    if env::var_os("CARGO_FEATURE_EMBED_INPUTS").is_some() {
+        let manifest_dir = env::var("CARGO_MANIFEST_DIR").unwrap();
+
+        for year in 2015..=2100 {
+            let year_directory = Path::new(&manifest_dir).join(format!("src/year{year}"));
+            if !year_directory.is_dir() {
+                continue;
+            }
+
+            for day in 1..=25 {
+                let day_directory = year_directory.join(format!("day{day}"));
+                if !day_directory.is_dir() {
+                    continue;
+                }
+                println!("cargo:rerun-if-changed={}", day_directory.display());
+
+                for file_name in DAY_FILES {
+                    let path = day_directory.join(file_name);
+                    let encrypted = day_directory.join(format!("{file_name}.enc"));
+                    println!("cargo:rerun-if-changed={}", encrypted.display());
+
+                    // Only plain files can be embedded, an encrypted one would
+                    // silently leave the binary reading from disk.
+                    if !path.is_file() && encrypted.is_file() {
+                        panic!(
+                            "embed-inputs: {} is only stored encrypted, run \
+                             `advent-2024 inputs decrypt` before building",
+                            encrypted.display()
+                        );
+                    }
+                    if path.is_file() {
+                        println!("cargo:rerun-if-changed={}", path.display());
+                        entries += &format!(
+                            "    ({year}, {day}, {file_name:?}, include_str!({:?})),\n",
+                            path.display().to_string()
+                        );
+                    }
+                }
+            }
+        }
+    }
+
+    // Any source file can change an answer, shared helpers included, so the
+    // answer cache keys on all of them.
+    let manifest_dir = env::var("CARGO_MANIFEST_DIR").unwrap();
+    let source_directory = Path::new(&manifest_dir).join("src");
+    println!("cargo:rerun-if-changed={}", source_directory.display());
+    fs::write(
+        Path::new(&out_dir).join("source_hash.rs"),
+        format!(
+            "pub const SOURCE_HASH: u64 = {:#x};\n",
+            source_hash(&source_directory)
+        ),
+    )
+    .unwrap();
+
+    fs::write(
+        Path::new(&out_dir).join("embedded_inputs.rs"),
+        format!("pub const EMBEDDED_FILES: &[(usize, usize, &str, &str)] = &[\n{entries}];\n"),
+    )
+    .unwrap();
+}
+
+/// FNV-1a over the path and contents of every `.rs` file under `directory`, in
+/// path order, so it only changes when a source file does.
+fn source_hash(directory: &Path) -> u64 {
+    let mut files = vec![];
+    collect_sources(directory, &mut files);
+    files.sort();
+
+    let mut hash: u64 = 0xcbf29ce484222325;
+    for path in files {
+        let relative = path.strip_prefix(directory).unwrap().to_string_lossy();
+        let contents = fs::read(&path).unwrap();
+        for byte in relative
+            .as_bytes()
+            .iter()
+            .chain([0].iter())
+            .chain(&contents)
+        {
+            hash ^= *byte as u64;
+            hash = hash.wrapping_mul(0x100000001b3);
+        }
+    }
+
+    return hash;
+}
+
+fn collect_sources(directory: &Path, files: &mut Vec<PathBuf>) {
+    for entry in fs::read_dir(directory).unwrap() {
+        let path = entry.unwrap().path();
+        if path.is_dir() {
+            collect_sources(&path, files);
+        } else if path.extension().is_some_and(|extension| extension == "rs") {
+            files.push(path);
+        }
+    }
+}
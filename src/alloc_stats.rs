@@ -0,0 +1,145 @@
+use std::{
+    alloc::{GlobalAlloc, Layout, System},
+    sync::atomic::{AtomicUsize, Ordering},
+};
+
+static ALLOCATIONS: AtomicUsize = AtomicUsize::new(0);
+static BYTES_ALLOCATED: AtomicUsize = AtomicUsize::new(0);
+static CURRENT_BYTES: AtomicUsize = AtomicUsize::new(0);
+static PEAK_BYTES: AtomicUsize = AtomicUsize::new(0);
+
+/// Wraps the system allocator and keeps running totals of allocations, so the
+/// runner can report how much memory each day and part churns through.
+///
+/// Only counts anything once installed with `#[global_allocator]` in the binary.
+pub struct CountingAllocator;
+
+unsafe impl GlobalAlloc for CountingAllocator {
+    unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+        let pointer = System.alloc(layout);
+        if !pointer.is_null() {
+            record_allocation(layout.size());
+        }
+        return pointer;
+    }
+
+    unsafe fn alloc_zeroed(&self, layout: Layout) -> *mut u8 {
+        let pointer = System.alloc_zeroed(layout);
+        if !pointer.is_null() {
+            record_allocation(layout.size());
+        }
+        return pointer;
+    }
+
+    unsafe fn dealloc(&self, pointer: *mut u8, layout: Layout) {
+        System.dealloc(pointer, layout);
+        CURRENT_BYTES.fetch_sub(layout.size(), Ordering::Relaxed);
+    }
+
+    unsafe fn realloc(&self, pointer: *mut u8, layout: Layout, new_size: usize) -> *mut u8 {
+        let new_pointer = System.realloc(pointer, layout, new_size);
+        if !new_pointer.is_null() {
+            CURRENT_BYTES.fetch_sub(layout.size(), Ordering::Relaxed);
+            record_allocation(new_size);
+        }
+        return new_pointer;
+    }
+}
+
+fn record_allocation(size: usize) {
+    ALLOCATIONS.fetch_add(1, Ordering::Relaxed);
+    BYTES_ALLOCATED.fetch_add(size, Ordering::Relaxed);
+    let current = CURRENT_BYTES.fetch_add(size, Ordering::Relaxed) + size;
+    PEAK_BYTES.fetch_max(current, Ordering::Relaxed);
+}
+
+#[derive(Debug, Default, Clone, Copy, PartialEq)]
+pub struct AllocationReport {
+    pub allocations: usize,
+    pub bytes_allocated: usize,
+    pub peak_bytes: usize,
+    /// Peak resident set size while the part ran, when the platform lets the
+    /// peak be reset first. Includes whatever the process already held.
+    pub peak_resident_bytes: Option<usize>,
+}
+
+/// Runs `function` and reports what it allocated. The peak is measured relative
+/// to the live heap at the start, so memory held by earlier runs isn't counted.
+pub fn measure<T>(function: impl FnOnce() -> T) -> (T, AllocationReport) {
+    let allocations_before = ALLOCATIONS.load(Ordering::Relaxed);
+    let bytes_before = BYTES_ALLOCATED.load(Ordering::Relaxed);
+    let current_before = CURRENT_BYTES.load(Ordering::Relaxed);
+    PEAK_BYTES.store(current_before, Ordering::Relaxed);
+
+    let result = function();
+
+    let report = AllocationReport {
+        allocations: ALLOCATIONS.load(Ordering::Relaxed) - allocations_before,
+        bytes_allocated: BYTES_ALLOCATED.load(Ordering::Relaxed) - bytes_before,
+        peak_bytes: PEAK_BYTES
+            .load(Ordering::Relaxed)
+            .saturating_sub(current_before),
+        peak_resident_bytes: None,
+    };
+
+    return (result, report);
+}
+
+/// Resets the process's peak resident set size to what it holds now, so the
+/// next `peak_resident_kilobytes` only covers what ran since. Linux only,
+/// returns false where the peak can't be reset.
+pub fn reset_peak_resident() -> bool {
+    return std::fs::write("/proc/self/clear_refs", "5").is_ok();
+}
+
+/// Peak resident set size of the whole process in kilobytes since it started
+/// or since `reset_peak_resident`, read from `/proc/self/status`. Returns
+/// `None` on platforms without procfs.
+pub fn peak_resident_kilobytes() -> Option<usize> {
+    let status = std::fs::read_to_string("/proc/self/status").ok()?;
+    let line = status.lines().find(|line| line.starts_with("VmHWM:"))?;
+
+    return line
+        .split_whitespace()
+        .nth(1)
+        .and_then(|value| value.parse().ok());
+}
+
+pub fn format_bytes(bytes: usize) -> String {
+    let units = ["B", "KiB", "MiB", "GiB"];
+    let mut value = bytes as f64;
+    let mut unit = 0;
+
+    while value >= 1024.0 && unit < units.len() - 1 {
+        value /= 1024.0;
+        unit += 1;
+    }
+
+    if unit == 0 {
+        return format!("{} {}", bytes, units[unit]);
+    }
+    return format!("{:.1} {}", value, units[unit]);
+}
+
+#[test]
+fn format_bytes_picks_sensible_units() {
+    assert_eq!(format_bytes(512), "512 B");
+    assert_eq!(format_bytes(2048), "2.0 KiB");
+    assert_eq!(format_bytes(5 * 1024 * 1024 + 512 * 1024), "5.5 MiB");
+}
+
+#[test]
+fn measure_counts_allocations_made_through_the_allocator() {
+    let layout = Layout::from_size_align(4096, 8).unwrap();
+
+    let (_, report) = measure(|| unsafe {
+        let first = CountingAllocator.alloc(layout);
+        let second = CountingAllocator.alloc(layout);
+        CountingAllocator.dealloc(first, layout);
+        CountingAllocator.dealloc(second, layout);
+    });
+
+    assert_eq!(report.allocations, 2);
+    assert_eq!(report.bytes_allocated, 8192);
+    assert_eq!(report.peak_bytes, 8192);
+}
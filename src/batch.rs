@@ -0,0 +1,266 @@
+use std::{
+    collections::BTreeSet,
+    fs, io,
+    path::{Path, PathBuf},
+    time::Duration,
+};
+
+use crate::{
+    cache::{AnswerCache, DEFAULT_CACHE_PATH},
+    registry::{find_solutions, latest_year, puzzle_info},
+    runner::{run_solution, run_solution_with_cache, Outcome, RunOptions, RunResult},
+};
+
+/// Batch runs always have a time budget, a single slow input shouldn't hold up
+/// everybody else's.
+pub const DEFAULT_TIME_BUDGET: Duration = Duration::from_secs(30);
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct BatchInput {
+    pub user: String,
+    pub year: usize,
+    pub day: usize,
+    pub path: PathBuf,
+}
+
+pub struct BatchResult {
+    pub user: String,
+    pub result: RunResult,
+}
+
+/// Finds every `<user>/<day>.txt` below `directory`, which belongs to the latest
+/// year, and every `<user>/<year>/<day>.txt`. Day files may also be named
+/// `day<day>.txt`, anything else is ignored.
+pub fn find_batch_inputs(directory: &Path) -> io::Result<Vec<BatchInput>> {
+    let mut inputs = vec![];
+
+    for user_entry in fs::read_dir(directory)? {
+        let user_entry = user_entry?;
+        if !user_entry.file_type()?.is_dir() {
+            continue;
+        }
+        let user = user_entry.file_name().to_string_lossy().to_string();
+
+        for entry in fs::read_dir(user_entry.path())? {
+            let entry = entry?;
+            let file_name = entry.file_name().to_string_lossy().to_string();
+
+            if entry.file_type()?.is_dir() {
+                if let Ok(year) = file_name.parse::<usize>() {
+                    inputs.extend(find_day_files(&user, year, &entry.path())?);
+                }
+            }
+        }
+        inputs.extend(find_day_files(&user, latest_year(), &user_entry.path())?);
+    }
+
+    inputs.sort_by(|input_a, input_b| {
+        (input_a.year, input_a.day, &input_a.user).cmp(&(input_b.year, input_b.day, &input_b.user))
+    });
+
+    return Ok(inputs);
+}
+
+fn find_day_files(user: &str, year: usize, directory: &Path) -> io::Result<Vec<BatchInput>> {
+    let mut inputs = vec![];
+
+    for day_entry in fs::read_dir(directory)? {
+        let day_entry = day_entry?;
+        let file_name = day_entry.file_name().to_string_lossy().to_string();
+
+        if let Some(day) = parse_day_file_name(&file_name) {
+            inputs.push(BatchInput {
+                user: user.to_string(),
+                year,
+                day,
+                path: day_entry.path(),
+            });
+        }
+    }
+
+    return Ok(inputs);
+}
+
+fn parse_day_file_name(file_name: &str) -> Option<usize> {
+    let stem = file_name.strip_suffix(".txt")?;
+    let day = stem.strip_prefix("day").unwrap_or(stem);
+
+    return day.parse().ok();
+}
+
+pub fn run_batch(directory: &Path, options: &RunOptions) -> io::Result<Vec<BatchResult>> {
+    let inputs = find_batch_inputs(directory)?;
+    let mut cache = AnswerCache::load(Path::new(DEFAULT_CACHE_PATH));
+    let mut results = vec![];
+
+    for input in inputs {
+        let options = RunOptions {
+            report_allocations: options.report_allocations,
+            timeout: Some(options.timeout.unwrap_or(DEFAULT_TIME_BUDGET)),
+            use_cache: options.use_cache,
+            validate_input: options.validate_input,
+            input_path: Some(input.path.clone()),
+            explain: false,
+        };
+
+        for solution in find_solutions(input.year, input.day, None) {
+            println!("=== {} ===", input.user);
+            let result = if options.use_cache {
+                run_solution_with_cache(&solution, &options, &mut cache)
+            } else {
+                run_solution(&solution, &options)
+            };
+
+            results.push(BatchResult {
+                user: input.user.clone(),
+                result,
+            });
+        }
+    }
+
+    if options.use_cache {
+        if let Err(error) = cache.save() {
+            eprintln!("could not save the answer cache: {error}");
+        }
+    }
+
+    return Ok(results);
+}
+
+fn describe_cell(result: &RunResult) -> String {
+    match result.outcome {
+        Outcome::Finished => result.answer.clone().unwrap_or_default(),
+        Outcome::Cancelled | Outcome::TimedOut => "TIMEOUT".to_string(),
+        Outcome::Panicked => "PANIC".to_string(),
+        Outcome::InvalidInput => "INVALID".to_string(),
+    }
+}
+
+/// One row per day and part, one column per user. Inputs that panicked, ran out
+/// of time or failed validation are listed again underneath so they're hard to miss.
+pub fn format_batch_matrix(results: &[BatchResult]) -> String {
+    let users = results
+        .iter()
+        .map(|batch_result| batch_result.user.clone())
+        .collect::<BTreeSet<String>>();
+    let rows = results
+        .iter()
+        .map(|batch_result| {
+            (
+                batch_result.result.solution.year,
+                batch_result.result.solution.day,
+                batch_result.result.solution.part,
+            )
+        })
+        .collect::<BTreeSet<(usize, usize, usize)>>();
+
+    let mut table = vec![];
+    let mut header = vec!["year".to_string(), "day".to_string(), "puzzle".to_string()];
+    header.extend(users.iter().cloned());
+    table.push(header);
+
+    for (year, day, part) in &rows {
+        let title = puzzle_info(*year, *day)
+            .map(|puzzle| puzzle.title.to_string())
+            .unwrap_or_default();
+        let mut row = vec![year.to_string(), format!("{day}.{part}"), title];
+        for user in &users {
+            let cell = results
+                .iter()
+                .find(|batch_result| {
+                    batch_result.user == *user
+                        && batch_result.result.solution.year == *year
+                        && batch_result.result.solution.day == *day
+                        && batch_result.result.solution.part == *part
+                })
+                .map(|batch_result| describe_cell(&batch_result.result))
+                .unwrap_or("-".to_string());
+            row.push(cell);
+        }
+        table.push(row);
+    }
+
+    let widths = (0..table[0].len())
+        .map(|column| table.iter().map(|row| row[column].len()).max().unwrap())
+        .collect::<Vec<usize>>();
+
+    let mut output = String::new();
+    for row in &table {
+        let cells = row
+            .iter()
+            .zip(&widths)
+            .map(|(cell, width)| format!("{:>width$}", cell, width = width))
+            .collect::<Vec<String>>();
+        output += cells.join("  ").trim_end();
+        output += "\n";
+    }
+
+    let failures = results
+        .iter()
+        .filter(|batch_result| batch_result.result.outcome != Outcome::Finished)
+        .collect::<Vec<&BatchResult>>();
+
+    if !failures.is_empty() {
+        output += "\nflagged inputs:\n";
+        for failure in failures {
+            output += &format!(
+                "  {} {} day {} part {}: {}\n",
+                failure.user,
+                failure.result.solution.year,
+                failure.result.solution.day,
+                failure.result.solution.part,
+                describe_cell(&failure.result).to_lowercase()
+            );
+        }
+    }
+
+    return output;
+}
+
+#[test]
+fn day_file_names_are_parsed() {
+    assert_eq!(parse_day_file_name("7.txt"), Some(7));
+    assert_eq!(parse_day_file_name("07.txt"), Some(7));
+    assert_eq!(parse_day_file_name("day12.txt"), Some(12));
+    assert_eq!(parse_day_file_name("notes.txt"), None);
+    assert_eq!(parse_day_file_name("7.md"), None);
+}
+
+#[test]
+fn batch_inputs_are_found_per_user() {
+    let directory = std::env::temp_dir().join(format!("advent-batch-test-{}", std::process::id()));
+    fs::create_dir_all(directory.join("alice")).unwrap();
+    fs::create_dir_all(directory.join("bob")).unwrap();
+    fs::write(directory.join("alice/11.txt"), "125 17").unwrap();
+    fs::write(directory.join("bob/day11.txt"), "0 1").unwrap();
+    fs::write(directory.join("bob/README.md"), "").unwrap();
+    fs::create_dir_all(directory.join("bob/2023")).unwrap();
+    fs::write(directory.join("bob/2023/1.txt"), "1abc2").unwrap();
+
+    let inputs = find_batch_inputs(&directory).unwrap();
+    fs::remove_dir_all(&directory).unwrap();
+
+    assert_eq!(
+        inputs,
+        vec![
+            BatchInput {
+                user: "bob".to_string(),
+                year: 2023,
+                day: 1,
+                path: directory.join("bob/2023/1.txt"),
+            },
+            BatchInput {
+                user: "alice".to_string(),
+                year: 2024,
+                day: 11,
+                path: directory.join("alice/11.txt"),
+            },
+            BatchInput {
+                user: "bob".to_string(),
+                year: 2024,
+                day: 11,
+                path: directory.join("bob/day11.txt"),
+            },
+        ]
+    );
+}
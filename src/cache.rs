@@ -0,0 +1,177 @@
+use std::{
+    collections::HashMap,
+    fs, io,
+    path::{Path, PathBuf},
+};
+
+use crate::{input::read_input_files, registry::Solution};
+
+pub const DEFAULT_CACHE_PATH: &str = ".cache/answers.tsv";
+
+/// Identifies one answer: a change to the input, any source file or the crate
+/// version produces a different key, so stale answers are never returned.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct CacheKey {
+    pub year: usize,
+    pub day: usize,
+    pub part: usize,
+    pub input_hash: u64,
+    pub solver_hash: u64,
+}
+
+impl CacheKey {
+    /// Returns `None` when the input can't be read, in which case the solver
+    /// would fail anyway and there is nothing worth caching.
+    pub fn for_solution(solution: &Solution, input_path: Option<&Path>) -> Option<CacheKey> {
+        let input = read_input_files(solution.year, solution.day, input_path)?;
+
+        return Some(CacheKey {
+            year: solution.year,
+            day: solution.day,
+            part: solution.part,
+            input_hash: fnv1a(&input),
+            solver_hash: solver_hash(),
+        });
+    }
+}
+
+// Generated by build.rs, a hash of every source file under src/.
+include!(concat!(env!("OUT_DIR"), "/source_hash.rs"));
+
+/// Days share parsing and other helpers, so every source file counts, not just
+/// the day's own.
+pub fn solver_hash() -> u64 {
+    let version = env!("CARGO_PKG_VERSION");
+
+    return fnv1a(format!("{version}\n{SOURCE_HASH:x}").as_bytes());
+}
+
+/// 64 bit FNV-1a. Unlike `DefaultHasher` it is stable between toolchains, which
+/// matters because the hashes are written to disk.
+pub fn fnv1a(bytes: &[u8]) -> u64 {
+    let mut hash: u64 = 0xcbf29ce484222325;
+    for byte in bytes {
+        hash ^= *byte as u64;
+        hash = hash.wrapping_mul(0x100000001b3);
+    }
+    return hash;
+}
+
+pub struct AnswerCache {
+    path: PathBuf,
+    entries: HashMap<CacheKey, String>,
+}
+
+impl AnswerCache {
+    /// A missing or unreadable cache file is treated as an empty cache.
+    pub fn load(path: &Path) -> AnswerCache {
+        let contents = fs::read_to_string(path).unwrap_or_default();
+
+        let entries = contents.lines().filter_map(parse_line).collect();
+
+        return AnswerCache {
+            path: path.to_path_buf(),
+            entries,
+        };
+    }
+
+    pub fn get(&self, key: &CacheKey) -> Option<&String> {
+        return self.entries.get(key);
+    }
+
+    /// Older answers for the same input are dropped, they can never be hit again.
+    pub fn insert(&mut self, key: CacheKey, answer: String) {
+        self.entries.retain(|existing, _| {
+            existing.year != key.year
+                || existing.day != key.day
+                || existing.part != key.part
+                || existing.input_hash != key.input_hash
+        });
+        self.entries.insert(key, answer);
+    }
+
+    pub fn save(&self) -> io::Result<()> {
+        if let Some(directory) = self.path.parent() {
+            fs::create_dir_all(directory)?;
+        }
+
+        let mut keys = self.entries.keys().collect::<Vec<&CacheKey>>();
+        keys.sort_by_key(|key| (key.year, key.day, key.part, key.input_hash));
+
+        let mut contents = String::new();
+        for key in keys {
+            contents += &format!(
+                "{}\t{}\t{}\t{:016x}\t{:016x}\t{}\n",
+                key.year, key.day, key.part, key.input_hash, key.solver_hash, self.entries[key]
+            );
+        }
+
+        return fs::write(&self.path, contents);
+    }
+}
+
+fn parse_line(line: &str) -> Option<(CacheKey, String)> {
+    let mut parts = line.splitn(6, '\t');
+
+    let key = CacheKey {
+        year: parts.next()?.parse().ok()?,
+        day: parts.next()?.parse().ok()?,
+        part: parts.next()?.parse().ok()?,
+        input_hash: u64::from_str_radix(parts.next()?, 16).ok()?,
+        solver_hash: u64::from_str_radix(parts.next()?, 16).ok()?,
+    };
+    let answer = parts.next()?.to_string();
+
+    return Some((key, answer));
+}
+
+#[test]
+fn fnv1a_matches_reference_values() {
+    assert_eq!(fnv1a(b""), 0xcbf29ce484222325);
+    assert_eq!(fnv1a(b"a"), 0xaf63dc4c8601ec8c);
+    assert_eq!(fnv1a(b"foobar"), 0x85944171f73967e8);
+}
+
+#[test]
+fn cache_round_trips_through_disk() {
+    let path = std::env::temp_dir().join(format!("advent-cache-test-{}.tsv", std::process::id()));
+    let key = CacheKey {
+        year: 2024,
+        day: 18,
+        part: 2,
+        input_hash: 1,
+        solver_hash: 2,
+    };
+
+    let mut cache = AnswerCache::load(&path);
+    assert_eq!(cache.get(&key), None);
+    cache.insert(key, "6,1".to_string());
+    cache.save().unwrap();
+
+    let cache = AnswerCache::load(&path);
+    fs::remove_file(&path).unwrap();
+
+    assert_eq!(cache.get(&key), Some(&"6,1".to_string()));
+}
+
+#[test]
+fn new_solver_version_replaces_old_answer() {
+    let old_key = CacheKey {
+        year: 2024,
+        day: 1,
+        part: 1,
+        input_hash: 1,
+        solver_hash: 1,
+    };
+    let new_key = CacheKey {
+        solver_hash: 2,
+        ..old_key
+    };
+
+    let mut cache = AnswerCache::load(Path::new("does/not/exist.tsv"));
+    cache.insert(old_key, "11".to_string());
+    cache.insert(new_key, "12".to_string());
+
+    assert_eq!(cache.get(&old_key), None);
+    assert_eq!(cache.get(&new_key), Some(&"12".to_string()));
+}
@@ -0,0 +1,200 @@
+use std::{
+    io::{self, IsTerminal, Write},
+    sync::{
+        atomic::{AtomicBool, Ordering},
+        Mutex,
+    },
+    time::{Duration, Instant},
+};
+
+use crate::explain::Explanation;
+
+/// Returned by solvers that noticed their [`Context`] was cancelled and stopped early.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Cancelled;
+
+/// Shared between the runner and a solver, so long running solvers can report
+/// progress and stop cleanly when the run is cancelled or hits its timeout.
+pub struct Context {
+    deadline: Option<Instant>,
+    cancelled: AtomicBool,
+    show_progress: bool,
+    last_progress_draw: Mutex<Option<Instant>>,
+    /// Only collected in explain mode, otherwise solvers skip building them.
+    explanations: Option<Mutex<Vec<Explanation>>>,
+}
+
+const PROGRESS_REDRAW_INTERVAL: Duration = Duration::from_millis(100);
+const PROGRESS_BAR_WIDTH: usize = 40;
+
+impl Context {
+    pub fn new() -> Self {
+        return Context {
+            deadline: None,
+            cancelled: AtomicBool::new(false),
+            show_progress: false,
+            last_progress_draw: Mutex::new(None),
+            explanations: None,
+        };
+    }
+
+    pub fn with_timeout(mut self, timeout: Duration) -> Self {
+        self.deadline = Some(Instant::now() + timeout);
+        return self;
+    }
+
+    /// Draws progress bars on stderr, but only when stderr is a terminal.
+    pub fn with_progress(mut self) -> Self {
+        self.show_progress = io::stderr().is_terminal();
+        return self;
+    }
+
+    /// Collects the walkthroughs solvers add with `explain`.
+    pub fn with_explanations(mut self) -> Self {
+        self.explanations = Some(Mutex::new(vec![]));
+        return self;
+    }
+
+    pub fn deadline(&self) -> Option<Instant> {
+        return self.deadline;
+    }
+
+    pub fn cancel(&self) {
+        self.cancelled.store(true, Ordering::Relaxed);
+    }
+
+    pub fn is_cancelled(&self) -> bool {
+        if self.cancelled.load(Ordering::Relaxed) {
+            return true;
+        }
+
+        if let Some(deadline) = self.deadline {
+            if Instant::now() >= deadline {
+                self.cancel();
+                return true;
+            }
+        }
+
+        return false;
+    }
+
+    /// Call this regularly inside long loops and return early with `?`.
+    pub fn check(&self) -> Result<(), Cancelled> {
+        if self.is_cancelled() {
+            return Err(Cancelled);
+        }
+        return Ok(());
+    }
+
+    pub fn progress(&self, done: usize, total: usize) {
+        if !self.show_progress || total == 0 {
+            return;
+        }
+
+        let mut last_draw = self.last_progress_draw.lock().unwrap();
+        let now = Instant::now();
+        if let Some(last) = *last_draw {
+            if now - last < PROGRESS_REDRAW_INTERVAL && done < total {
+                return;
+            }
+        }
+        *last_draw = Some(now);
+
+        eprint!("\r{}", render_progress_bar(done, total));
+        io::stderr().flush().ok();
+    }
+
+    /// Adds a step to the walkthrough. `markdown` is only called in explain
+    /// mode, so solvers can build large tables without slowing normal runs.
+    pub fn explain(&self, title: &str, markdown: impl FnOnce() -> String) {
+        if let Some(explanations) = &self.explanations {
+            explanations.lock().unwrap().push(Explanation {
+                title: title.to_string(),
+                markdown: markdown(),
+            });
+        }
+    }
+
+    pub fn take_explanations(&self) -> Vec<Explanation> {
+        return match &self.explanations {
+            Some(explanations) => std::mem::take(&mut *explanations.lock().unwrap()),
+            None => vec![],
+        };
+    }
+
+    /// Clears the progress bar so the solver's own output starts on a clean line.
+    pub fn finish_progress(&self) {
+        let mut last_draw = self.last_progress_draw.lock().unwrap();
+        if self.show_progress && last_draw.is_some() {
+            eprint!("\r{}\r", " ".repeat(PROGRESS_BAR_WIDTH + 24));
+            io::stderr().flush().ok();
+        }
+        *last_draw = None;
+    }
+}
+
+impl Default for Context {
+    fn default() -> Self {
+        return Context::new();
+    }
+}
+
+fn render_progress_bar(done: usize, total: usize) -> String {
+    let done = done.min(total);
+    let filled = done * PROGRESS_BAR_WIDTH / total;
+
+    return format!(
+        "[{}{}] {:>3}% ({}/{})",
+        "#".repeat(filled),
+        "-".repeat(PROGRESS_BAR_WIDTH - filled),
+        done * 100 / total,
+        done,
+        total
+    );
+}
+
+#[test]
+fn progress_bar_renders_proportionally() {
+    assert_eq!(
+        render_progress_bar(10, 40),
+        format!("[{}{}]  25% (10/40)", "#".repeat(10), "-".repeat(30))
+    );
+    assert_eq!(
+        render_progress_bar(50, 40),
+        format!("[{}] 100% (40/40)", "#".repeat(40))
+    );
+}
+
+#[test]
+fn context_is_cancelled_after_timeout() {
+    let context = Context::new().with_timeout(Duration::ZERO);
+
+    assert_eq!(context.check(), Err(Cancelled));
+}
+
+#[test]
+fn context_can_be_cancelled_manually() {
+    let context = Context::new();
+    assert_eq!(context.check(), Ok(()));
+
+    context.cancel();
+
+    assert_eq!(context.check(), Err(Cancelled));
+}
+
+#[test]
+fn explanations_are_only_built_when_asked_for() {
+    let context = Context::new();
+    context.explain("skipped", || panic!("built outside explain mode"));
+    assert_eq!(context.take_explanations(), vec![]);
+
+    let context = Context::new().with_explanations();
+    context.explain("sorted lists", || "| 1 | 3 |\n".to_string());
+    assert_eq!(
+        context.take_explanations(),
+        vec![Explanation {
+            title: "sorted lists".to_string(),
+            markdown: "| 1 | 3 |\n".to_string(),
+        }]
+    );
+}
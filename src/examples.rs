@@ -0,0 +1,258 @@
+use std::{
+    fs, io,
+    path::{Path, PathBuf},
+};
+
+use regex::Regex;
+
+use crate::input::day_directory;
+
+pub const ANSWERS_FILE: &str = "answers.tsv";
+
+/// An emphasised answer from the puzzle text, belonging to the example block
+/// that came before it.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ExampleAnswer {
+    /// Index into `Examples::inputs`.
+    pub example: usize,
+    pub part: usize,
+    pub answer: String,
+}
+
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct Examples {
+    pub inputs: Vec<String>,
+    pub answers: Vec<ExampleAnswer>,
+}
+
+/// Where a day's examples live, e.g. `src/year2024/day1/examples`.
+pub fn examples_directory(year: usize, day: usize) -> PathBuf {
+    return day_directory(year, day).join("examples");
+}
+
+fn example_file_name(index: usize) -> String {
+    return format!("example{}.txt", index + 1);
+}
+
+/// Pulls the `<pre><code>` blocks and the emphasised `<code><em>` answers out of
+/// a saved puzzle page. Each `<article>` is one part. Part two usually reuses
+/// the last example of part one, a block repeated there is only kept once. Only
+/// the last answer after a block is kept, as the puzzle text builds up to the
+/// total.
+pub fn extract_examples(html: &str) -> Examples {
+    let article = Regex::new(r"(?s)<article[^>]*>(.*?)</article>").unwrap();
+    let token = Regex::new(
+        r"(?s)<pre><code>(?P<block>.*?)</code></pre>|<code><em>(?P<answer>.*?)</em></code>|<em><code>(?P<answer_outside>.*?)</code></em>",
+    )
+    .unwrap();
+
+    let mut examples = Examples::default();
+    let mut current_example = None;
+    for (part_index, article) in article.captures_iter(html).enumerate() {
+        let part = part_index + 1;
+
+        for captures in token.captures_iter(&article[1]) {
+            if let Some(block) = captures.name("block") {
+                let text = decode_html(block.as_str());
+                let index = match examples.inputs.iter().position(|input| *input == text) {
+                    Some(index) => index,
+                    None => {
+                        examples.inputs.push(text);
+                        examples.inputs.len() - 1
+                    }
+                };
+                current_example = Some(index);
+                continue;
+            }
+
+            let Some(example) = current_example else {
+                continue;
+            };
+            let answer = captures
+                .name("answer")
+                .or(captures.name("answer_outside"))
+                .map(|answer| decode_html(answer.as_str()))
+                .unwrap_or_default();
+
+            examples
+                .answers
+                .retain(|existing| existing.example != example || existing.part != part);
+            examples.answers.push(ExampleAnswer {
+                example,
+                part,
+                answer,
+            });
+        }
+    }
+
+    return examples;
+}
+
+/// Drops the highlighting tags inside a block and undoes the entities the
+/// puzzle pages use.
+fn decode_html(text: &str) -> String {
+    let tag = Regex::new(r"<[^>]+>").unwrap();
+
+    return tag
+        .replace_all(text, "")
+        .replace("&lt;", "<")
+        .replace("&gt;", ">")
+        .replace("&quot;", "\"")
+        .replace("&#39;", "'")
+        .replace("&amp;", "&");
+}
+
+/// Writes one `exampleN.txt` per block and an `answers.tsv` of example file,
+/// part and answer.
+pub fn write_examples(directory: &Path, examples: &Examples) -> io::Result<Vec<PathBuf>> {
+    fs::create_dir_all(directory)?;
+    let mut written = vec![];
+
+    for (index, input) in examples.inputs.iter().enumerate() {
+        let path = directory.join(example_file_name(index));
+        fs::write(&path, input)?;
+        written.push(path);
+    }
+
+    let answers = examples
+        .answers
+        .iter()
+        .map(|answer| {
+            format!(
+                "{}\t{}\t{}\n",
+                example_file_name(answer.example),
+                answer.part,
+                answer.answer
+            )
+        })
+        .collect::<String>();
+    let path = directory.join(ANSWERS_FILE);
+    fs::write(&path, answers)?;
+    written.push(path);
+
+    return Ok(written);
+}
+
+/// Reads back what `write_examples` wrote, so tests can run every example.
+pub fn read_examples(directory: &Path) -> io::Result<Examples> {
+    let mut examples = Examples::default();
+    let mut index = 0;
+    while let Ok(input) = fs::read_to_string(directory.join(example_file_name(index))) {
+        examples.inputs.push(input);
+        index += 1;
+    }
+
+    for line in fs::read_to_string(directory.join(ANSWERS_FILE))?.lines() {
+        let [file_name, part, answer] = line.split('\t').collect::<Vec<&str>>()[..] else {
+            continue;
+        };
+        let example =
+            (0..examples.inputs.len()).find(|index| example_file_name(*index) == file_name);
+        if let (Some(example), Ok(part)) = (example, part.parse()) {
+            examples.answers.push(ExampleAnswer {
+                example,
+                part,
+                answer: answer.to_string(),
+            });
+        }
+    }
+
+    return Ok(examples);
+}
+
+/// Runs each of the day's parts on every extracted example with an answer
+/// and checks it gets that answer. Panics listing every mismatch.
+#[cfg(test)]
+pub(crate) fn assert_examples_solve(year: usize, day: usize) {
+    let directory = examples_directory(year, day);
+    let examples = read_examples(&directory)
+        .unwrap_or_else(|error| panic!("{year} day {day} examples not readable: {error}"));
+    assert!(
+        !examples.answers.is_empty(),
+        "{year} day {day} has no example answers"
+    );
+
+    let mut mismatches = vec![];
+    for example in &examples.answers {
+        let solution = crate::registry::find_solutions(year, day, Some(example.part))
+            .pop()
+            .unwrap_or_else(|| panic!("no solution registered for {year} day {day}"));
+
+        let path = directory.join(example_file_name(example.example));
+        crate::input::set_input_override(Some(path.clone()));
+        let answer = (solution.run)(&crate::context::Context::new());
+        crate::input::set_input_override(None);
+
+        if answer.as_ref().ok() != Some(&example.answer) {
+            mismatches.push(format!(
+                "{} part {}: expected {}, got {:?}",
+                path.display(),
+                example.part,
+                example.answer,
+                answer.ok()
+            ));
+        }
+    }
+
+    assert!(mismatches.is_empty(), "{}", mismatches.join("\n"));
+}
+
+#[test]
+fn extracts_blocks_and_the_last_answer_after_each() {
+    let html = "<main>
+<article class=\"day-desc\"><h2>--- Day 1 ---</h2>
+<p>For example:</p>
+<pre><code>3   4
+4   3
+</code></pre>
+<p>Distances add up: <code>1 + 1</code> is <code><em>1</em></code>, a total of <code><em>2</em></code>.</p>
+<pre><code>x -&gt; <em>y</em> &amp;&amp; z
+</code></pre>
+</article>
+<p>Your puzzle answer was <code>1234</code>.</p>
+<article class=\"day-desc\"><h2>--- Part Two ---</h2>
+<pre><code>3   4
+4   3
+</code></pre>
+<p>The similarity score is <em><code>9</code></em>.</p>
+</article>
+</main>";
+
+    let examples = extract_examples(html);
+
+    assert_eq!(examples.inputs, vec!["3   4\n4   3\n", "x -> y && z\n"]);
+    assert_eq!(
+        examples.answers,
+        vec![
+            ExampleAnswer {
+                example: 0,
+                part: 1,
+                answer: "2".to_string()
+            },
+            ExampleAnswer {
+                example: 0,
+                part: 2,
+                answer: "9".to_string()
+            },
+        ]
+    );
+}
+
+#[test]
+fn written_examples_read_back_the_same() {
+    let directory = std::env::temp_dir().join(format!("examples-{}", std::process::id()));
+    let examples = Examples {
+        inputs: vec!["1 2\n".to_string(), "3 4\n".to_string()],
+        answers: vec![ExampleAnswer {
+            example: 1,
+            part: 2,
+            answer: "7".to_string(),
+        }],
+    };
+
+    write_examples(&directory, &examples).unwrap();
+    let read_back = read_examples(&directory).unwrap();
+    fs::remove_dir_all(&directory).unwrap();
+
+    assert_eq!(read_back, examples);
+}
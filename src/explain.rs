@@ -0,0 +1,98 @@
+use std::{fs, io, path::Path};
+
+use crate::{
+    registry::puzzle_info,
+    runner::{Outcome, RunResult},
+};
+
+pub const DEFAULT_EXPLAIN_PATH: &str = "explain.md";
+
+/// One titled step of a solver's walkthrough, its body is Markdown.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Explanation {
+    pub title: String,
+    pub markdown: String,
+}
+
+/// A Markdown table, every row needs as many cells as there are headers. Pipes
+/// in headers and cells are escaped so they can't split a cell.
+pub fn markdown_table(headers: &[&str], rows: &[Vec<String>]) -> String {
+    let headers: Vec<String> = headers.iter().map(|header| escape_cell(header)).collect();
+    let mut output = format!("| {} |\n", headers.join(" | "));
+    output += &format!("|{}\n", " --- |".repeat(headers.len()));
+    for row in rows {
+        let cells: Vec<String> = row.iter().map(|cell| escape_cell(cell)).collect();
+        output += &format!("| {} |\n", cells.join(" | "));
+    }
+
+    return output;
+}
+
+fn escape_cell(cell: &str) -> String {
+    return cell.replace('|', "\\|");
+}
+
+/// The walkthrough of every run, in the order they ran. Parts whose solver
+/// doesn't explain itself still get their answer listed.
+pub fn format_explain_report(results: &[RunResult]) -> String {
+    let mut output = "# Walkthrough\n".to_string();
+
+    for result in results {
+        let solution = result.solution;
+        let label = puzzle_info(solution.year, solution.day)
+            .map(|puzzle| format!(" ({})", puzzle.label()))
+            .unwrap_or_default();
+        output += &format!(
+            "\n## {} day {} part {}{}\n\n",
+            solution.year, solution.day, solution.part, label
+        );
+
+        match (&result.answer, result.outcome) {
+            (Some(answer), _) => output += &format!("Answer: `{answer}`\n"),
+            (None, outcome) => output += &format!("No answer: {outcome:?}\n"),
+        }
+        if result.explanations.is_empty() && result.outcome == Outcome::Finished {
+            output += "\nThis solver doesn't explain its steps yet.\n";
+        }
+
+        for explanation in &result.explanations {
+            output += &format!("\n### {}\n\n{}", explanation.title, explanation.markdown);
+            if !explanation.markdown.ends_with('\n') {
+                output += "\n";
+            }
+        }
+    }
+
+    return output;
+}
+
+pub fn write_explain_report(path: &Path, results: &[RunResult]) -> io::Result<()> {
+    if let Some(directory) = path.parent() {
+        fs::create_dir_all(directory)?;
+    }
+
+    return fs::write(path, format_explain_report(results));
+}
+
+#[test]
+fn markdown_table_has_a_header_separator() {
+    let rows = vec![
+        vec!["1".to_string(), "3".to_string()],
+        vec!["2".to_string(), "4".to_string()],
+    ];
+
+    assert_eq!(
+        markdown_table(&["left", "right"], &rows),
+        "| left | right |\n| --- | --- |\n| 1 | 3 |\n| 2 | 4 |\n"
+    );
+}
+
+#[test]
+fn markdown_table_escapes_pipes_in_every_cell() {
+    let rows = vec![vec!["81 || 40".to_string(), "a|b".to_string()]];
+
+    assert_eq!(
+        markdown_table(&["x|y", "z"], &rows),
+        "| x\\|y | z |\n| --- | --- |\n| 81 \\|\\| 40 | a\\|b |\n"
+    );
+}
@@ -0,0 +1,216 @@
+use std::{
+    cmp::Reverse,
+    collections::BinaryHeap,
+    env, fs,
+    io::{self, BufReader, BufWriter, Read, Write},
+    path::PathBuf,
+    process,
+    sync::atomic::{AtomicUsize, Ordering},
+};
+
+static NEXT_SORTER: AtomicUsize = AtomicUsize::new(0);
+
+/// A value the sorter can spill, written as a fixed number of bytes.
+pub trait Spill: Ord + Copy {
+    fn write_to(self, writer: &mut impl Write) -> io::Result<()>;
+
+    /// `None` at the end of the run.
+    fn read_from(reader: &mut impl Read) -> io::Result<Option<Self>>;
+}
+
+impl Spill for i64 {
+    fn write_to(self, writer: &mut impl Write) -> io::Result<()> {
+        return writer.write_all(&self.to_le_bytes());
+    }
+
+    fn read_from(reader: &mut impl Read) -> io::Result<Option<i64>> {
+        return Ok(read_bytes(reader)?.map(i64::from_le_bytes));
+    }
+}
+
+impl Spill for u64 {
+    fn write_to(self, writer: &mut impl Write) -> io::Result<()> {
+        return writer.write_all(&self.to_le_bytes());
+    }
+
+    fn read_from(reader: &mut impl Read) -> io::Result<Option<u64>> {
+        return Ok(read_bytes(reader)?.map(u64::from_le_bytes));
+    }
+}
+
+/// Sorts more numbers than fit in memory. Values are buffered `chunk_size` at
+/// a time, each full buffer is sorted and spilled to a run file in a temporary
+/// directory, and `finish` merges the runs back in order.
+pub struct ExternalSorter<T: Spill = i64> {
+    chunk_size: usize,
+    buffer: Vec<T>,
+    directory: PathBuf,
+    runs: Vec<PathBuf>,
+}
+
+impl<T: Spill> ExternalSorter<T> {
+    pub fn new(chunk_size: usize) -> Self {
+        let directory = env::temp_dir().join(format!(
+            "advent-sort-{}-{}",
+            process::id(),
+            NEXT_SORTER.fetch_add(1, Ordering::Relaxed)
+        ));
+
+        return ExternalSorter {
+            chunk_size: chunk_size.max(1),
+            buffer: vec![],
+            directory,
+            runs: vec![],
+        };
+    }
+
+    pub fn push(&mut self, value: T) -> io::Result<()> {
+        self.buffer.push(value);
+        if self.buffer.len() >= self.chunk_size {
+            self.spill()?;
+        }
+
+        return Ok(());
+    }
+
+    /// How many run files were written so far.
+    pub fn runs(&self) -> usize {
+        return self.runs.len();
+    }
+
+    fn spill(&mut self) -> io::Result<()> {
+        self.buffer.sort_unstable();
+        fs::create_dir_all(&self.directory)?;
+        let path = self.directory.join(format!("run{}", self.runs.len()));
+
+        let mut writer = BufWriter::new(fs::File::create(&path)?);
+        for value in self.buffer.drain(..) {
+            value.write_to(&mut writer)?;
+        }
+        writer.flush()?;
+
+        self.runs.push(path);
+        return Ok(());
+    }
+
+    /// Everything pushed, smallest first. Without any spilled runs the values
+    /// never touch the disk.
+    pub fn finish(mut self) -> io::Result<SortedValues<T>> {
+        self.buffer.sort_unstable();
+        let buffer = std::mem::take(&mut self.buffer);
+        let mut readers = vec![];
+        let mut heap = BinaryHeap::new();
+
+        for (index, path) in self.runs.iter().enumerate() {
+            let mut reader = BufReader::new(fs::File::open(path)?);
+            if let Some(value) = T::read_from(&mut reader)? {
+                heap.push(Reverse((value, index)));
+            }
+            readers.push(reader);
+        }
+        if !buffer.is_empty() {
+            heap.push(Reverse((buffer[0], readers.len())));
+        }
+
+        return Ok(SortedValues {
+            sorter: self,
+            readers,
+            buffer,
+            buffer_position: 0,
+            heap,
+        });
+    }
+}
+
+impl<T: Spill> Drop for ExternalSorter<T> {
+    fn drop(&mut self) {
+        if !self.runs.is_empty() {
+            let _ = fs::remove_dir_all(&self.directory);
+        }
+    }
+}
+
+fn read_bytes<const N: usize>(reader: &mut impl Read) -> io::Result<Option<[u8; N]>> {
+    let mut bytes = [0; N];
+    match reader.read_exact(&mut bytes) {
+        Ok(()) => return Ok(Some(bytes)),
+        Err(error) if error.kind() == io::ErrorKind::UnexpectedEof => return Ok(None),
+        Err(error) => return Err(error),
+    }
+}
+
+/// A k-way merge of the sorted runs and whatever was left in memory. The run
+/// files are removed when it is dropped.
+pub struct SortedValues<T: Spill = i64> {
+    // Only kept to clean up the run files once the merge is done.
+    #[allow(dead_code)]
+    sorter: ExternalSorter<T>,
+    readers: Vec<BufReader<fs::File>>,
+    buffer: Vec<T>,
+    buffer_position: usize,
+    heap: BinaryHeap<Reverse<(T, usize)>>,
+}
+
+impl<T: Spill> Iterator for SortedValues<T> {
+    type Item = io::Result<T>;
+
+    fn next(&mut self) -> Option<io::Result<T>> {
+        let Reverse((value, source)) = self.heap.pop()?;
+
+        // The in-memory buffer is the source after the last run.
+        let next = if source == self.readers.len() {
+            self.buffer_position += 1;
+            Ok(self.buffer.get(self.buffer_position).copied())
+        } else {
+            T::read_from(&mut self.readers[source])
+        };
+        match next {
+            Ok(Some(next)) => self.heap.push(Reverse((next, source))),
+            Ok(None) => {}
+            Err(error) => return Some(Err(error)),
+        }
+
+        return Some(Ok(value));
+    }
+}
+
+#[test]
+fn sorts_across_spilled_runs() {
+    let values = (0..1000)
+        .map(|index| (index * 7919 % 1009) as i64 - 500)
+        .collect::<Vec<i64>>();
+    let mut sorter = ExternalSorter::new(64);
+    for value in &values {
+        sorter.push(*value).unwrap();
+    }
+    assert_eq!(sorter.runs(), 15);
+
+    let directory = sorter.directory.clone();
+    let sorted = sorter
+        .finish()
+        .unwrap()
+        .collect::<io::Result<Vec<i64>>>()
+        .unwrap();
+
+    let mut expected = values;
+    expected.sort();
+    assert_eq!(sorted, expected);
+    assert!(!directory.exists());
+}
+
+#[test]
+fn unsigned_values_sort_past_the_signed_range() {
+    let values = [u64::MAX, 0, 1 << 63, 7, u64::MAX - 1];
+    let mut sorter = ExternalSorter::new(2);
+    for value in values {
+        sorter.push(value).unwrap();
+    }
+
+    let sorted = sorter
+        .finish()
+        .unwrap()
+        .collect::<io::Result<Vec<u64>>>()
+        .unwrap();
+
+    assert_eq!(sorted, vec![0, 7, 1 << 63, u64::MAX - 1, u64::MAX]);
+}
@@ -0,0 +1,125 @@
+use std::{
+    cell::RefCell,
+    path::{Path, PathBuf},
+};
+
+use crate::input_store::{self, StoreError};
+
+// Generated by build.rs, empty unless the `embed-inputs` feature is enabled.
+include!(concat!(env!("OUT_DIR"), "/embedded_inputs.rs"));
+
+thread_local! {
+    static INPUT_OVERRIDE: RefCell<Option<PathBuf>> = const { RefCell::new(None) };
+}
+
+/// Where a day's solution and the files it reads live, e.g. `src/year2024/day7`.
+pub fn day_directory(year: usize, day: usize) -> PathBuf {
+    return PathBuf::from(format!("src/year{year}/day{day}"));
+}
+
+pub fn input_path(year: usize, day: usize) -> PathBuf {
+    return day_directory(year, day).join("input.txt");
+}
+
+/// The input the solvers on this thread will read for `day`: the override if one
+/// is set, otherwise the input committed next to the day.
+pub fn resolve_input_path(year: usize, day: usize) -> PathBuf {
+    return INPUT_OVERRIDE
+        .with(|input_override| input_override.borrow().clone())
+        .unwrap_or_else(|| input_path(year, day));
+}
+
+/// Makes every `read_input` on the current thread read `path` instead, so the
+/// runner can solve other people's inputs without touching the solvers.
+pub fn set_input_override(path: Option<PathBuf>) {
+    INPUT_OVERRIDE.with(|input_override| *input_override.borrow_mut() = path);
+}
+
+/// Reads the input for `day`. Without an override, an input embedded in the
+/// binary wins over the one on disk. Encrypted inputs are decrypted on the way.
+pub fn read_input(year: usize, day: usize) -> String {
+    let input_override = INPUT_OVERRIDE.with(|input_override| input_override.borrow().clone());
+
+    return try_read_input(year, day, input_override.as_deref())
+        .unwrap_or_else(|error| panic!("{year} day {day} input not readable: {error}"));
+}
+
+/// Like `read_input`, but for an explicit `input` instead of this thread's
+/// override, and without panicking.
+pub fn try_read_input(year: usize, day: usize, input: Option<&Path>) -> Result<String, StoreError> {
+    return match (input, embedded_file(year, day, "input.txt")) {
+        (Some(path), _) => input_store::read_to_string(path),
+        (None, Some(embedded)) => Ok(embedded.to_string()),
+        (None, None) => input_store::read_to_string(&input_path(year, day)),
+    };
+}
+
+/// Reads another file kept next to a day's solution, like day 14's floor size.
+/// These belong to the solver rather than the input, so they're never
+/// overridden.
+pub fn read_day_file(year: usize, day: usize, file_name: &str) -> String {
+    if let Some(embedded) = embedded_file(year, day, file_name) {
+        return embedded.to_string();
+    }
+
+    let path = day_directory(year, day).join(file_name);
+
+    return input_store::read_to_string(&path)
+        .unwrap_or_else(|error| panic!("{year} day {day} file not readable: {error}"));
+}
+
+/// A file compiled into the binary by the `embed-inputs` feature.
+pub fn embedded_file(year: usize, day: usize, file_name: &str) -> Option<&'static str> {
+    return EMBEDDED_FILES
+        .iter()
+        .find(|(embedded_year, embedded_day, embedded_name, _)| {
+            *embedded_year == year && *embedded_day == day && *embedded_name == file_name
+        })
+        .map(|(_, _, _, contents)| *contents);
+}
+
+/// Everything the solvers for `day` read when given `input`, or the default
+/// input when it's `None`. Day 14 keeps the floor size next to its input,
+/// because the example and the real puzzle use different floors.
+pub fn read_input_files(year: usize, day: usize, input: Option<&Path>) -> Option<Vec<u8>> {
+    let mut contents = try_read_input(year, day, input).ok()?.into_bytes();
+
+    let size_path = day_directory(year, day).join("size.txt");
+    if let Some(embedded) = embedded_file(year, day, "size.txt") {
+        contents.extend(embedded.as_bytes());
+    } else if let Ok(size) = input_store::read(&size_path) {
+        contents.extend(size);
+    }
+
+    return Some(contents);
+}
+
+#[test]
+fn input_override_only_applies_to_current_thread() {
+    set_input_override(Some(PathBuf::from("inputs/alice/7.txt")));
+
+    let other_thread = std::thread::spawn(|| resolve_input_path(2024, 7))
+        .join()
+        .unwrap();
+
+    assert_eq!(
+        resolve_input_path(2024, 7),
+        PathBuf::from("inputs/alice/7.txt")
+    );
+    assert_eq!(other_thread, PathBuf::from("src/year2024/day7/input.txt"));
+
+    set_input_override(None);
+    assert_eq!(
+        resolve_input_path(2024, 7),
+        PathBuf::from("src/year2024/day7/input.txt")
+    );
+}
+
+#[test]
+fn embedded_inputs_match_the_files_they_came_from() {
+    for (year, day, file_name, contents) in EMBEDDED_FILES {
+        let on_disk = std::fs::read_to_string(day_directory(*year, *day).join(file_name)).unwrap();
+
+        assert_eq!(*contents, on_disk, "{year} day {day} {file_name}");
+    }
+}
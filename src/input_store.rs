@@ -0,0 +1,270 @@
+use std::{
+    env, fmt, fs, io,
+    path::{Path, PathBuf},
+};
+
+use chacha20poly1305::{
+    aead::{Aead, AeadCore, KeyInit, OsRng},
+    ChaCha20Poly1305, Key, Nonce,
+};
+use sha2::{Digest, Sha256};
+
+use crate::{input::input_path, registry::YEARS};
+
+pub const KEY_VARIABLE: &str = "ADVENT_INPUT_KEY";
+pub const KEY_FILE_VARIABLE: &str = "ADVENT_INPUT_KEY_FILE";
+pub const DEFAULT_KEY_FILE: &str = ".input-key";
+
+/// Encrypted inputs sit next to the plain ones with this extension added.
+pub const ENCRYPTED_EXTENSION: &str = "enc";
+
+/// Written in front of every encrypted file so a plain input is never mistaken
+/// for one.
+const MAGIC: &[u8; 8] = b"AOCENC1\n";
+const NONCE_LENGTH: usize = 12;
+
+#[derive(Debug)]
+pub enum StoreError {
+    MissingKey,
+    Io(PathBuf, io::Error),
+    NotEncrypted(PathBuf),
+    WrongKey(PathBuf),
+}
+
+impl fmt::Display for StoreError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            StoreError::MissingKey => write!(
+                f,
+                "no input key: set {KEY_VARIABLE}, point {KEY_FILE_VARIABLE} at a key file \
+                 or write the key to {DEFAULT_KEY_FILE}"
+            ),
+            StoreError::Io(path, error) => write!(f, "{:?}: {error}", path),
+            StoreError::NotEncrypted(path) => write!(f, "{:?} is not an encrypted input", path),
+            StoreError::WrongKey(path) => write!(
+                f,
+                "{:?} could not be decrypted, the input key is wrong or the file is damaged",
+                path
+            ),
+        }
+    }
+}
+
+impl std::error::Error for StoreError {}
+
+/// The symmetric key inputs are encrypted with. Any text works as a key, it is
+/// hashed down to the 256 bits the cipher needs.
+#[derive(Clone)]
+pub struct InputKey(Key);
+
+impl InputKey {
+    pub fn new(secret: &str) -> Self {
+        let digest = Sha256::digest(secret.trim().as_bytes());
+        return InputKey(Key::clone_from_slice(&digest));
+    }
+
+    /// Looks for the key in `ADVENT_INPUT_KEY`, then in the file named by
+    /// `ADVENT_INPUT_KEY_FILE`, then in `.input-key`.
+    pub fn from_environment() -> Result<InputKey, StoreError> {
+        if let Ok(secret) = env::var(KEY_VARIABLE) {
+            if !secret.trim().is_empty() {
+                return Ok(InputKey::new(&secret));
+            }
+        }
+
+        let key_file = env::var(KEY_FILE_VARIABLE).unwrap_or(DEFAULT_KEY_FILE.to_string());
+        return InputKey::from_file(Path::new(&key_file));
+    }
+
+    pub fn from_file(path: &Path) -> Result<InputKey, StoreError> {
+        let secret = match fs::read_to_string(path) {
+            Ok(secret) => secret,
+            Err(error) if error.kind() == io::ErrorKind::NotFound => {
+                return Err(StoreError::MissingKey)
+            }
+            Err(error) => return Err(StoreError::Io(path.to_path_buf(), error)),
+        };
+        if secret.trim().is_empty() {
+            return Err(StoreError::MissingKey);
+        }
+
+        return Ok(InputKey::new(&secret));
+    }
+
+    pub fn encrypt(&self, plain: &[u8]) -> Vec<u8> {
+        let cipher = ChaCha20Poly1305::new(&self.0);
+        let nonce = ChaCha20Poly1305::generate_nonce(&mut OsRng);
+        let encrypted = cipher
+            .encrypt(&nonce, plain)
+            .expect("encrypting into memory can't fail");
+
+        let mut contents = MAGIC.to_vec();
+        contents.extend(nonce);
+        contents.extend(encrypted);
+        return contents;
+    }
+
+    /// `path` is only used to say which file was wrong.
+    pub fn decrypt(&self, contents: &[u8], path: &Path) -> Result<Vec<u8>, StoreError> {
+        let Some(rest) = contents.strip_prefix(MAGIC) else {
+            return Err(StoreError::NotEncrypted(path.to_path_buf()));
+        };
+        if rest.len() < NONCE_LENGTH {
+            return Err(StoreError::WrongKey(path.to_path_buf()));
+        }
+        let (nonce, encrypted) = rest.split_at(NONCE_LENGTH);
+
+        let cipher = ChaCha20Poly1305::new(&self.0);
+        return cipher
+            .decrypt(Nonce::from_slice(nonce), encrypted)
+            .map_err(|_| StoreError::WrongKey(path.to_path_buf()));
+    }
+}
+
+pub fn encrypted_path(path: &Path) -> PathBuf {
+    let mut encrypted = path.as_os_str().to_owned();
+    encrypted.push(format!(".{ENCRYPTED_EXTENSION}"));
+    return PathBuf::from(encrypted);
+}
+
+pub fn is_encrypted_path(path: &Path) -> bool {
+    return path
+        .extension()
+        .is_some_and(|extension| extension == ENCRYPTED_EXTENSION);
+}
+
+/// Reads `path`, decrypting it when it's an encrypted input. When `path` is
+/// missing but its encrypted twin exists, that one is read instead, so solvers
+/// don't need to know which of the two is committed.
+pub fn read(path: &Path) -> Result<Vec<u8>, StoreError> {
+    let path = if !path.exists() && encrypted_path(path).exists() {
+        encrypted_path(path)
+    } else {
+        path.to_path_buf()
+    };
+
+    let contents = fs::read(&path).map_err(|error| StoreError::Io(path.clone(), error))?;
+    if !is_encrypted_path(&path) {
+        return Ok(contents);
+    }
+
+    return InputKey::from_environment()?.decrypt(&contents, &path);
+}
+
+pub fn read_to_string(path: &Path) -> Result<String, StoreError> {
+    let contents = read(path)?;
+
+    return String::from_utf8(contents).map_err(|error| {
+        StoreError::Io(
+            path.to_path_buf(),
+            io::Error::new(io::ErrorKind::InvalidData, error),
+        )
+    });
+}
+
+/// Every day's plain input or every day's encrypted input, in year and day
+/// order.
+fn stored_inputs(encrypted: bool) -> Vec<PathBuf> {
+    return YEARS
+        .iter()
+        .flat_map(|year| (1..=25).map(|day| input_path(*year, day)))
+        .map(|path| {
+            if encrypted {
+                encrypted_path(&path)
+            } else {
+                path
+            }
+        })
+        .filter(|path| path.exists())
+        .collect();
+}
+
+/// Encrypts every plain input and removes it, so only the encrypted copy is
+/// left to commit.
+pub fn encrypt_inputs(key: &InputKey) -> Result<Vec<PathBuf>, StoreError> {
+    let mut written = vec![];
+
+    for path in stored_inputs(false) {
+        let plain = fs::read(&path).map_err(|error| StoreError::Io(path.clone(), error))?;
+        let encrypted = encrypted_path(&path);
+
+        fs::write(&encrypted, key.encrypt(&plain))
+            .map_err(|error| StoreError::Io(encrypted.clone(), error))?;
+        fs::remove_file(&path).map_err(|error| StoreError::Io(path.clone(), error))?;
+        written.push(encrypted);
+    }
+
+    return Ok(written);
+}
+
+/// Writes the plain input next to every encrypted one, keeping the encrypted
+/// copies.
+pub fn decrypt_inputs(key: &InputKey) -> Result<Vec<PathBuf>, StoreError> {
+    let mut written = vec![];
+
+    for path in stored_inputs(true) {
+        let contents = fs::read(&path).map_err(|error| StoreError::Io(path.clone(), error))?;
+        let plain_path = path.with_extension("");
+
+        fs::write(&plain_path, key.decrypt(&contents, &path)?)
+            .map_err(|error| StoreError::Io(plain_path.clone(), error))?;
+        written.push(plain_path);
+    }
+
+    return Ok(written);
+}
+
+/// Re-encrypts every encrypted input with `new_key`. Everything is decrypted
+/// before anything is written, so a wrong old key leaves the store untouched.
+pub fn rekey_inputs(old_key: &InputKey, new_key: &InputKey) -> Result<Vec<PathBuf>, StoreError> {
+    let mut plain_inputs = vec![];
+    for path in stored_inputs(true) {
+        let contents = fs::read(&path).map_err(|error| StoreError::Io(path.clone(), error))?;
+        plain_inputs.push((old_key.decrypt(&contents, &path)?, path));
+    }
+
+    let mut written = vec![];
+    for (plain, path) in plain_inputs {
+        fs::write(&path, new_key.encrypt(&plain))
+            .map_err(|error| StoreError::Io(path.clone(), error))?;
+        written.push(path);
+    }
+
+    return Ok(written);
+}
+
+#[test]
+fn encrypted_inputs_round_trip() {
+    let key = InputKey::new("correct horse battery staple");
+    let path = Path::new("src/year2024/day1/input.txt.enc");
+
+    let encrypted = key.encrypt(b"3   4\n4   3\n");
+
+    assert!(encrypted.starts_with(MAGIC));
+    assert_eq!(key.decrypt(&encrypted, path).unwrap(), b"3   4\n4   3\n");
+}
+
+#[test]
+fn wrong_key_and_plain_files_are_reported() {
+    let path = Path::new("src/year2024/day1/input.txt.enc");
+    let encrypted = InputKey::new("one key").encrypt(b"125 17");
+
+    assert!(matches!(
+        InputKey::new("another key").decrypt(&encrypted, path),
+        Err(StoreError::WrongKey(_))
+    ));
+    assert!(matches!(
+        InputKey::new("one key").decrypt(b"125 17", path),
+        Err(StoreError::NotEncrypted(_))
+    ));
+}
+
+#[test]
+fn missing_key_file_is_a_missing_key() {
+    let error = InputKey::from_file(Path::new("no/such/key/file"))
+        .err()
+        .unwrap();
+
+    assert!(matches!(error, StoreError::MissingKey));
+    assert!(error.to_string().contains(KEY_VARIABLE));
+}
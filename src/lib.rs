@@ -1,22 +1,23 @@
-pub mod day1;
-pub mod day2;
-pub mod day3;
-pub mod day4;
-pub mod day5;
-pub mod day6;
-pub mod day7;
-pub mod day8;
-pub mod day9;
-pub mod day10;
-pub mod day11;
-pub mod day12;
-pub mod day13;
-pub mod day14;
-pub mod day15;
-pub mod day16;
-pub mod day17;
-pub mod day18;
-pub mod day19;
-pub mod day20;
-pub mod day21;
-pub mod day22;
\ No newline at end of file
+pub mod alloc_stats;
+pub mod batch;
+pub mod cache;
+pub mod context;
+pub mod examples;
+pub mod explain;
+pub mod external_sort;
+pub mod input;
+pub mod input_store;
+pub mod memo;
+pub mod metrics;
+pub mod number_theory;
+pub mod order_statistics;
+pub mod parse;
+pub mod perf_history;
+pub mod registry;
+pub mod runner;
+pub mod simulation;
+pub mod snapshot;
+pub mod validate;
+#[cfg(target_os = "linux")]
+pub mod watch;
+pub mod year2024;
@@ -1,68 +1,474 @@
-use advent_2024::{day16::day_16_part_1, day17::{day_17_part_1, day_17_part_2}, day18::{day_18_part_1, day_18_part_2}, day19::{day_19_part_1, day_19_part_2}, day20::{day_20_part_1, day_20_part_2}, day21::{day_21_part_1, day_21_part_2}, day22::day_22_part_1};
+use std::{
+    env, fs,
+    io::{self, BufRead, BufReader},
+    path::Path,
+    process,
+    time::Duration,
+};
+
+use advent_2024::{
+    alloc_stats::CountingAllocator,
+    batch::{format_batch_matrix, run_batch},
+    examples::{examples_directory, extract_examples, write_examples},
+    explain::{write_explain_report, DEFAULT_EXPLAIN_PATH},
+    input::read_input,
+    input_store::{decrypt_inputs, encrypt_inputs, rekey_inputs, InputKey},
+    perf_history::{
+        append_history, benchmark, current_commit, current_machine, format_perf_report,
+        load_history, perf_trends, DEFAULT_HISTORY_PATH, DEFAULT_SAMPLES,
+        DEFAULT_THRESHOLD_PERCENT,
+    },
+    registry::{
+        find_solutions, find_year_solutions, format_puzzle_list, latest_year, solutions, Solution,
+    },
+    runner::{run_solutions, RunOptions},
+    snapshot::{accept_pending, find_pending, review_pending},
+    year2024::day1::{
+        format_stream_statistics, stream_statistics, DistanceMetric, OnlineListPair,
+        DEFAULT_CHUNK_ROWS,
+    },
+    year2024::day2::{diagnose_reports, format_diagnoses, SafetyPolicy},
+};
+
+#[cfg(target_os = "linux")]
+use advent_2024::watch::watch_day;
+
+#[global_allocator]
+static ALLOCATOR: CountingAllocator = CountingAllocator;
+
+const USAGE: &str = "usage:
+    advent-2024 [run] [<year>] [<day> [<part>] | all] [options]
+    advent-2024 batch <directory> [options]
+    advent-2024 list [<year>]
+    advent-2024 watch [<year>] <day> [options]
+    advent-2024 bench [<year>] [<day> [<part>] | all] [--samples <count>]
+    advent-2024 perf-report [--threshold <percent>]
+    advent-2024 inputs encrypt | decrypt | rekey <new key file>
+    advent-2024 snapshots [review | accept]
+    advent-2024 examples [<year>] <day> <saved puzzle page>
+    advent-2024 day1-stats <location lists> [<rows per chunk>]
+    advent-2024 day1-online
+    advent-2024 day2-report [<reports>]
+
+options:
+    --alloc              report allocations and peak memory per part
+    --no-cache           always run the solvers, ignoring cached answers
+    --timeout <seconds>  give up on a part after this long
+    --no-validate        solve inputs even when they fail validation
+    --explain            write each solver's intermediate steps to explain.md
+    --samples <count>    runs per part when benchmarking, the median is kept
+    --threshold <percent>
+                         slowdown against the previous run that counts as a
+                         regression
+
+the year defaults to the latest one with solutions, `all` without a year runs
+every year. watch only re-runs when a day's inputs or examples change, restart
+it after editing the solution";
 
 fn main() {
     println!("Hello, Advent of Code!");
 
-    // day_1();
+    let mut options = RunOptions::default();
+    let mut samples = DEFAULT_SAMPLES;
+    let mut threshold_percent = DEFAULT_THRESHOLD_PERCENT;
+    let mut positional = vec![];
+
+    let mut arguments = env::args().skip(1);
+    while let Some(argument) = arguments.next() {
+        match argument.as_str() {
+            "--alloc" => options.report_allocations = true,
+            "--no-cache" => options.use_cache = false,
+            "--no-validate" => options.validate_input = false,
+            "--explain" => options.explain = true,
+            "--timeout" => {
+                let seconds = arguments
+                    .next()
+                    .and_then(|seconds| seconds.parse::<f64>().ok())
+                    .unwrap_or_else(|| exit_with_usage("--timeout needs a number of seconds"));
+                options.timeout = Some(Duration::from_secs_f64(seconds));
+            }
+            "--samples" => {
+                samples = arguments
+                    .next()
+                    .and_then(|count| count.parse::<usize>().ok())
+                    .filter(|count| *count > 0)
+                    .unwrap_or_else(|| exit_with_usage("--samples needs a positive count"));
+            }
+            "--threshold" => {
+                threshold_percent = arguments
+                    .next()
+                    .and_then(|percent| percent.parse::<f64>().ok())
+                    .unwrap_or_else(|| exit_with_usage("--threshold needs a percentage"));
+            }
+            "-h" | "--help" => {
+                println!("{USAGE}");
+                return;
+            }
+            _ => positional.push(argument),
+        }
+    }
+
+    match positional.first().map(|command| command.as_str()) {
+        Some("batch") => match &positional[1..] {
+            [directory] => batch(Path::new(directory), &options),
+            _ => exit_with_usage("batch needs exactly one directory"),
+        },
+        Some("list") => match split_year(&positional[1..]) {
+            (year, []) => print!("{}", format_puzzle_list(year)),
+            _ => exit_with_usage("list takes at most a year"),
+        },
+        Some("inputs") => inputs(&positional[1..]),
+        Some("snapshots") => snapshots(&positional[1..]),
+        Some("examples") => examples(&positional[1..]),
+        Some("day1-stats") => day1_stats(&positional[1..]),
+        Some("day1-online") => match &positional[1..] {
+            [] => day1_online(),
+            _ => exit_with_usage("day1-online takes no arguments"),
+        },
+        Some("day2-report") => day2_report(&positional[1..]),
+        Some("watch") => watch(&positional[1..], &options),
+        Some("bench") => bench(&positional[1..], &options, samples),
+        Some("perf-report") => match &positional[1..] {
+            [] => perf_report(threshold_percent),
+            _ => exit_with_usage("perf-report takes no arguments"),
+        },
+        Some("run") => run(&positional[1..], &options),
+        _ => run(&positional, &options),
+    }
+}
+
+fn run(positional: &[String], options: &RunOptions) {
+    let solutions_to_run =
+        select_solutions(positional).unwrap_or_else(|error| exit_with_usage(&error));
+
+    let results = run_solutions(&solutions_to_run, options);
 
-    // day_2();
+    if options.explain {
+        let path = Path::new(DEFAULT_EXPLAIN_PATH);
+        if let Err(error) = write_explain_report(path, &results) {
+            eprintln!("could not write the walkthrough: {error}");
+            process::exit(1);
+        }
+        println!();
+        println!("walkthrough written to {}", path.display());
+    }
+}
 
-    // day_3();
-    // day_3_part_2();
+fn batch(directory: &Path, options: &RunOptions) {
+    let results = run_batch(directory, options).unwrap_or_else(|error| {
+        eprintln!("could not read batch directory {:?}: {error}", directory);
+        process::exit(1);
+    });
 
-    // day_4_part_1();
-    // day_4_part_2();
+    println!();
+    print!("{}", format_batch_matrix(&results));
+}
 
-    // day_5_part_1();
-    // day_5_part_2();
+/// Encrypted input store maintenance. The current key comes from the
+/// environment, see `InputKey::from_environment`.
+fn inputs(positional: &[String]) {
+    let written = match positional {
+        [command] if command == "encrypt" => {
+            InputKey::from_environment().and_then(|key| encrypt_inputs(&key))
+        }
+        [command] if command == "decrypt" => {
+            InputKey::from_environment().and_then(|key| decrypt_inputs(&key))
+        }
+        [command, new_key_file] if command == "rekey" => {
+            InputKey::from_environment().and_then(|old_key| {
+                let new_key = InputKey::from_file(Path::new(new_key_file))?;
+                return rekey_inputs(&old_key, &new_key);
+            })
+        }
+        _ => exit_with_usage("inputs needs encrypt, decrypt or rekey <new key file>"),
+    };
 
-    // day_6_part_1();
-    // day_6_part_2();
-    // day_6_part_2_multithread();
+    match written {
+        Ok(written) => {
+            for path in written {
+                println!("wrote {}", path.display());
+            }
+        }
+        Err(error) => {
+            eprintln!("{error}");
+            process::exit(1);
+        }
+    }
+}
 
-    // day_7_part_1();
-    // day_7_part_2();
+/// Pending snapshots are written by failing snapshot tests, `review` shows how
+/// they differ from the golden files and `accept` replaces the golden files.
+fn snapshots(positional: &[String]) {
+    let accept = match positional {
+        [] => false,
+        [command] if command == "review" => false,
+        [command] if command == "accept" => true,
+        _ => exit_with_usage("snapshots takes review or accept"),
+    };
 
-    // day_8_part_1();
-    // day_8_part_2();
+    let pending = find_pending(Path::new("src")).unwrap_or_else(|error| {
+        eprintln!("could not look for pending snapshots: {error}");
+        process::exit(1);
+    });
+    if pending.is_empty() {
+        println!("no pending snapshots");
+        return;
+    }
 
-    // day_9_part_1();
-    // day_9_part_2();
+    for path in pending {
+        let result = if accept {
+            accept_pending(&path).map(|golden| format!("accepted {}", golden.display()))
+        } else {
+            review_pending(&path)
+        };
 
-    // day_10_part_1();
-    // day_10_part_2();
+        match result {
+            Ok(message) => println!("{message}"),
+            Err(error) => {
+                eprintln!("could not read {}: {error}", path.display());
+                process::exit(1);
+            }
+        }
+    }
+}
 
-    // day_11_part_1();
-    // day_11_part_2();
+/// Saves the examples of a puzzle page downloaded from the website next to
+/// the day, replacing any extracted before.
+fn examples(positional: &[String]) {
+    let (year, positional) = split_year(positional);
+    let year = year.unwrap_or(latest_year());
+    let (day, page) = match positional {
+        [day, page] => (
+            day.parse::<usize>()
+                .unwrap_or_else(|_| exit_with_usage(&format!("invalid day: {day}"))),
+            Path::new(page),
+        ),
+        _ => exit_with_usage("examples needs a day and a saved puzzle page"),
+    };
 
-    // day_12_part_1();
-    // day_12_part_2();
+    let html = fs::read_to_string(page).unwrap_or_else(|error| {
+        eprintln!("could not read {}: {error}", page.display());
+        process::exit(1);
+    });
+    let examples = extract_examples(&html);
+    if examples.inputs.is_empty() {
+        eprintln!("no example blocks found in {}", page.display());
+        process::exit(1);
+    }
 
-    // day_13_part_1();
-    // day_13_part_2();
+    match write_examples(&examples_directory(year, day), &examples) {
+        Ok(written) => {
+            for path in written {
+                println!("wrote {}", path.display());
+            }
+            println!("{} expected answer(s) found", examples.answers.len());
+        }
+        Err(error) => {
+            eprintln!("could not write the examples: {error}");
+            process::exit(1);
+        }
+    }
+}
 
-    // day_14_part_1();
-    // day_14_part_2();
+/// Day 1's statistics for location lists too large for `run`, read a row at a
+/// time.
+fn day1_stats(positional: &[String]) {
+    let (path, chunk_rows) = match positional {
+        [path] => (Path::new(path), DEFAULT_CHUNK_ROWS),
+        [path, chunk_rows] => (
+            Path::new(path),
+            chunk_rows
+                .parse::<usize>()
+                .ok()
+                .filter(|rows| *rows > 0)
+                .unwrap_or_else(|| exit_with_usage("rows per chunk needs a positive count")),
+        ),
+        _ => exit_with_usage("day1-stats needs a file of location lists"),
+    };
 
-    // day_15_part_1();
-    // day_15_part_2();
+    let statistics = fs::File::open(path)
+        .and_then(|file| stream_statistics(BufReader::new(file), chunk_rows))
+        .unwrap_or_else(|error| {
+            eprintln!("could not read {}: {error}", path.display());
+            process::exit(1);
+        });
 
-    // day_16_part_1();
+    print!("{}", format_stream_statistics(&statistics));
+}
 
-    // day_17_part_1();
-    // day_17_part_2();
+/// Reads day 1 rows from stdin as they are found. A blank line prints the
+/// totals so far, as does the end of the input.
+fn day1_online() {
+    let mut lists = OnlineListPair::new();
+    let print_totals = |lists: &OnlineListPair| {
+        println!(
+            "pairs: {}, total distance: {}, similarity score: {}",
+            lists.pairs(),
+            lists.distance(DistanceMetric::Absolute),
+            lists.similarity()
+        );
+    };
 
-    // day_18_part_1();
-    // day_18_part_2();
+    for (index, line) in io::stdin().lock().lines().enumerate() {
+        let line = line.unwrap_or_else(|error| {
+            eprintln!("could not read stdin: {error}");
+            process::exit(1);
+        });
+        if line.trim().is_empty() {
+            print_totals(&lists);
+            continue;
+        }
+        if let Err(error) = lists.push_row(index + 1, &line) {
+            eprintln!("{error}");
+            process::exit(1);
+        }
+    }
+
+    print_totals(&lists);
+}
+
+/// Why each day 2 report is safe or not, for the day's input or another file
+/// of reports.
+fn day2_report(positional: &[String]) {
+    let text = match positional {
+        [] => read_input(2024, 2),
+        [path] => fs::read_to_string(path).unwrap_or_else(|error| {
+            eprintln!("could not read {path}: {error}");
+            process::exit(1);
+        }),
+        _ => exit_with_usage("day2-report takes at most a file of reports"),
+    };
+
+    match diagnose_reports(&text, &SafetyPolicy::default()) {
+        Ok(diagnoses) => print!("{}", format_diagnoses(&diagnoses)),
+        Err(error) => {
+            eprintln!("{error}");
+            process::exit(1);
+        }
+    }
+}
 
-    // day_19_part_1();
-    // day_19_part_2();
+#[cfg(target_os = "linux")]
+fn watch(positional: &[String], options: &RunOptions) {
+    let (year, positional) = split_year(positional);
+    let year = year.unwrap_or(latest_year());
+    let day = match positional {
+        [day] => day
+            .parse::<usize>()
+            .unwrap_or_else(|_| exit_with_usage(&format!("invalid day: {day}"))),
+        _ => exit_with_usage("watch needs a day"),
+    };
+    if find_solutions(year, day, None).is_empty() {
+        exit_with_usage(&format!("no solution registered for {year} day {day}"));
+    }
+
+    if let Err(error) = watch_day(year, day, options) {
+        eprintln!("could not watch {year} day {day}: {error}");
+        process::exit(1);
+    }
+}
+
+/// Watching is built on inotify, which only exists on Linux.
+#[cfg(not(target_os = "linux"))]
+fn watch(_positional: &[String], _options: &RunOptions) {
+    eprintln!("watch is only supported on Linux");
+    process::exit(1);
+}
+
+fn bench(positional: &[String], options: &RunOptions, samples: usize) {
+    let solutions_to_run =
+        select_solutions(positional).unwrap_or_else(|error| exit_with_usage(&error));
+
+    let records = benchmark(&solutions_to_run, options, samples);
+    if let Err(error) = append_history(Path::new(DEFAULT_HISTORY_PATH), &records) {
+        eprintln!("could not save the performance history: {error}");
+        process::exit(1);
+    }
+
+    println!();
+    println!(
+        "recorded {} part(s) for {} on {}",
+        records.len(),
+        current_commit(),
+        current_machine()
+    );
+}
+
+fn perf_report(threshold_percent: f64) {
+    let history = load_history(Path::new(DEFAULT_HISTORY_PATH));
+    let trends = perf_trends(&history, &current_machine(), threshold_percent);
+    if trends.is_empty() {
+        println!("no benchmarks recorded on this machine yet, run `bench` first");
+        return;
+    }
+
+    print!("{}", format_perf_report(&trends, threshold_percent));
+    if trends.iter().any(|trend| trend.is_regression) {
+        process::exit(1);
+    }
+}
+
+fn exit_with_usage(error: &str) -> ! {
+    eprintln!("{error}");
+    eprintln!("{USAGE}");
+    process::exit(2);
+}
+
+/// Days never go past 25, so a leading number above that is an event year.
+fn split_year(positional: &[String]) -> (Option<usize>, &[String]) {
+    if let Some(year) = positional
+        .first()
+        .and_then(|first| first.parse::<usize>().ok())
+        .filter(|number| *number > 25)
+    {
+        return (Some(year), &positional[1..]);
+    }
+    return (None, positional);
+}
+
+fn select_solutions(positional: &[String]) -> Result<Vec<Solution>, String> {
+    let (year, positional) = split_year(positional);
+
+    match (year, positional) {
+        (None, []) => Ok(find_year_solutions(latest_year())
+            .last()
+            .into_iter()
+            .copied()
+            .collect()),
+        (None, [all]) if all == "all" => Ok(solutions()),
+        (Some(year), []) => find_year(year),
+        (Some(year), [all]) if all == "all" => find_year(year),
+        (year, [day]) => parse_and_find(year.unwrap_or(latest_year()), day, None),
+        (year, [day, part]) => parse_and_find(year.unwrap_or(latest_year()), day, Some(part)),
+        _ => Err("too many arguments".to_string()),
+    }
+}
+
+fn find_year(year: usize) -> Result<Vec<Solution>, String> {
+    let found = find_year_solutions(year);
+    if found.is_empty() {
+        return Err(format!("no solutions registered for {year}"));
+    }
+
+    return Ok(found);
+}
 
-    // day_20_part_1();
-    // day_20_part_2();
+fn parse_and_find(year: usize, day: &str, part: Option<&String>) -> Result<Vec<Solution>, String> {
+    let day = day
+        .parse::<usize>()
+        .map_err(|_| format!("invalid day: {day}"))?;
+    let part = match part {
+        Some(part) => Some(
+            part.parse::<usize>()
+                .map_err(|_| format!("invalid part: {part}"))?,
+        ),
+        None => None,
+    };
 
-    // day_21_part_1();
-    // day_21_part_2();
+    let found = find_solutions(year, day, part);
+    if found.is_empty() {
+        return Err(format!("no solution registered for {year} day {day}"));
+    }
 
-    day_22_part_1();
+    return Ok(found);
 }
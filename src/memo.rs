@@ -0,0 +1,66 @@
+use std::{collections::HashMap, hash::Hash};
+
+/// A cache for recursive functions keyed by their state. The function takes the
+/// memo as its first argument and recurses through `get_or_compute`, so every
+/// distinct state is only computed once.
+pub struct Memo<K, V> {
+    cache: HashMap<K, V>,
+}
+
+impl<K: Hash + Eq + Clone, V: Clone> Memo<K, V> {
+    pub fn new() -> Self {
+        return Memo {
+            cache: HashMap::new(),
+        };
+    }
+
+    /// The cached value for `key`, or the one `compute` returns. `compute` gets
+    /// the memo back to make its recursive calls with.
+    pub fn get_or_compute(&mut self, key: K, compute: impl FnOnce(&mut Self) -> V) -> V {
+        if let Some(value) = self.cache.get(&key) {
+            return value.clone();
+        }
+
+        let value = compute(self);
+        self.cache.insert(key, value.clone());
+        return value;
+    }
+
+    /// How many distinct states were computed.
+    pub fn len(&self) -> usize {
+        return self.cache.len();
+    }
+
+    pub fn is_empty(&self) -> bool {
+        return self.cache.is_empty();
+    }
+}
+
+impl<K: Hash + Eq + Clone, V: Clone> Default for Memo<K, V> {
+    fn default() -> Self {
+        return Memo::new();
+    }
+}
+
+#[test]
+fn recursion_through_the_memo_computes_each_state_once() {
+    fn fibonacci(memo: &mut Memo<u64, u64>, calls: &mut usize, n: u64) -> u64 {
+        return memo.get_or_compute(n, |memo| {
+            *calls += 1;
+            if n < 2 {
+                return n;
+            }
+            return fibonacci(memo, calls, n - 1) + fibonacci(memo, calls, n - 2);
+        });
+    }
+
+    let mut memo = Memo::new();
+    let mut calls = 0;
+
+    assert_eq!(
+        fibonacci(&mut memo, &mut calls, 90),
+        2_880_067_194_370_816_120
+    );
+    assert_eq!(calls, 91);
+    assert_eq!(memo.len(), 91);
+}
@@ -0,0 +1,59 @@
+use std::{cell::RefCell, collections::BTreeMap};
+
+thread_local! {
+    static COUNTERS: RefCell<BTreeMap<&'static str, usize>> = const { RefCell::new(BTreeMap::new()) };
+}
+
+/// The counters one run added to, sorted by name.
+pub type Counters = Vec<(&'static str, usize)>;
+
+/// Adds `amount` to the named counter on the current thread. Hot loops should
+/// count in a local and add once when they're done.
+pub fn add(name: &'static str, amount: usize) {
+    COUNTERS.with(|counters| *counters.borrow_mut().entry(name).or_default() += amount);
+}
+
+pub fn increment(name: &'static str) {
+    add(name, 1);
+}
+
+/// Runs `function` and returns what it counted. Counters are per thread, so the
+/// runner calls this on the solver's own thread.
+pub fn measure<T>(function: impl FnOnce() -> T) -> (T, Counters) {
+    let before = COUNTERS.with(|counters| std::mem::take(&mut *counters.borrow_mut()));
+
+    let result = function();
+
+    let counted = COUNTERS.with(|counters| {
+        return std::mem::replace(&mut *counters.borrow_mut(), before)
+            .into_iter()
+            .collect();
+    });
+    return (result, counted);
+}
+
+pub fn format_counters(counters: &Counters) -> String {
+    return counters
+        .iter()
+        .map(|(name, value)| format!("{name}: {value}"))
+        .collect::<Vec<String>>()
+        .join(", ");
+}
+
+#[test]
+fn measure_only_reports_what_the_function_counted() {
+    increment("outside");
+
+    let ((), counted) = measure(|| {
+        add("heap pushes", 3);
+        increment("nodes expanded");
+        increment("heap pushes");
+    });
+
+    assert_eq!(counted, vec![("heap pushes", 4), ("nodes expanded", 1)]);
+    assert_eq!(
+        format_counters(&counted),
+        "heap pushes: 4, nodes expanded: 1"
+    );
+    assert_eq!(measure(|| increment("outside")).1, vec![("outside", 1)]);
+}
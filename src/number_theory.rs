@@ -0,0 +1,199 @@
+// Integer math shared by the solvers. Everything works on `i128` and returns
+// `None` instead of overflowing.
+
+/// Always non-negative, `gcd(0, 0)` is 0.
+pub fn gcd(a: i128, b: i128) -> i128 {
+    let (mut a, mut b) = (a.unsigned_abs(), b.unsigned_abs());
+    while b != 0 {
+        (a, b) = (b, a % b);
+    }
+
+    return i128::try_from(a).expect("gcd of i128::MIN and 0 doesn't fit an i128");
+}
+
+pub fn lcm(a: i128, b: i128) -> Option<i128> {
+    if a == 0 || b == 0 {
+        return Some(0);
+    }
+
+    return (a / gcd(a, b)).checked_mul(b).map(i128::abs);
+}
+
+/// `(g, x, y)` with `a * x + b * y = g`, where `g` is the non-negative gcd.
+pub fn extended_gcd(a: i128, b: i128) -> (i128, i128, i128) {
+    let (mut old_remainder, mut remainder) = (a, b);
+    let (mut old_x, mut x) = (1, 0);
+    let (mut old_y, mut y) = (0, 1);
+
+    while remainder != 0 {
+        let quotient = old_remainder / remainder;
+        (old_remainder, remainder) = (remainder, old_remainder - quotient * remainder);
+        (old_x, x) = (x, old_x - quotient * x);
+        (old_y, y) = (y, old_y - quotient * y);
+    }
+
+    if old_remainder < 0 {
+        return (-old_remainder, -old_x, -old_y);
+    }
+    return (old_remainder, old_x, old_y);
+}
+
+/// `x` in `0..modulus` with `a * x ≡ 1`, if `a` and `modulus` are coprime.
+pub fn mod_inverse(a: i128, modulus: i128) -> Option<i128> {
+    if modulus <= 0 {
+        return None;
+    }
+
+    let (g, x, _) = extended_gcd(a.rem_euclid(modulus), modulus);
+    if g != 1 {
+        return None;
+    }
+    return Some(x.rem_euclid(modulus));
+}
+
+/// Combines `x ≡ residue (mod modulus)` congruences into one `(residue,
+/// modulus)` pair. The moduli don't have to be coprime, `None` means the
+/// congruences contradict each other or the result overflows.
+pub fn chinese_remainder(congruences: &[(i128, i128)]) -> Option<(i128, i128)> {
+    let mut combined = (0, 1);
+
+    for &(residue, modulus) in congruences {
+        if modulus <= 0 {
+            return None;
+        }
+        let (current_residue, current_modulus) = combined;
+        let residue = residue.rem_euclid(modulus);
+
+        // current_residue + current_modulus * k ≡ residue (mod modulus)
+        let (g, inverse, _) = extended_gcd(current_modulus, modulus);
+        let difference = residue.checked_sub(current_residue)?;
+        if difference % g != 0 {
+            return None;
+        }
+
+        let reduced_modulus = modulus / g;
+        let k = (difference / g)
+            .rem_euclid(reduced_modulus)
+            .checked_mul(inverse.rem_euclid(reduced_modulus))?
+            .rem_euclid(reduced_modulus);
+        let combined_modulus = current_modulus.checked_mul(reduced_modulus)?;
+        let combined_residue = current_modulus
+            .checked_mul(k)?
+            .checked_add(current_residue)?
+            .rem_euclid(combined_modulus);
+
+        combined = (combined_residue, combined_modulus);
+    }
+
+    return Some(combined);
+}
+
+/// Every integer solution of `a * x + b * y = c` is `(x + k * x_step, y - k *
+/// y_step)` for some integer `k`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct DiophantineSolutions {
+    pub x: i128,
+    pub y: i128,
+    pub x_step: i128,
+    pub y_step: i128,
+}
+
+/// `None` when there's no integer solution, or when `a` and `b` are both 0,
+/// where every pair or none solves it.
+pub fn solve_linear_diophantine(a: i128, b: i128, c: i128) -> Option<DiophantineSolutions> {
+    if a == 0 && b == 0 {
+        return None;
+    }
+
+    let (g, x, y) = extended_gcd(a, b);
+    if c % g != 0 {
+        return None;
+    }
+    let scale = c / g;
+
+    return Some(DiophantineSolutions {
+        x: x.checked_mul(scale)?,
+        y: y.checked_mul(scale)?,
+        x_step: b / g,
+        y_step: a / g,
+    });
+}
+
+/// The single integer solution of
+/// `a_1 * x + b_1 * y = c_1` and `a_2 * x + b_2 * y = c_2`. `None` when the
+/// solution isn't whole or not unique, or the arithmetic overflows.
+pub fn solve_2x2([a_1, b_1, c_1]: [i128; 3], [a_2, b_2, c_2]: [i128; 3]) -> Option<(i128, i128)> {
+    let determinant = a_1.checked_mul(b_2)?.checked_sub(b_1.checked_mul(a_2)?)?;
+    if determinant == 0 {
+        return None;
+    }
+
+    let x_numerator = c_1.checked_mul(b_2)?.checked_sub(b_1.checked_mul(c_2)?)?;
+    let y_numerator = a_1.checked_mul(c_2)?.checked_sub(c_1.checked_mul(a_2)?)?;
+    if x_numerator % determinant != 0 || y_numerator % determinant != 0 {
+        return None;
+    }
+
+    return Some((x_numerator / determinant, y_numerator / determinant));
+}
+
+#[test]
+fn gcd_and_lcm_ignore_signs() {
+    assert_eq!(gcd(12, -18), 6);
+    assert_eq!(gcd(0, 7), 7);
+    assert_eq!(gcd(0, 0), 0);
+    assert_eq!(lcm(4, -6), Some(12));
+    assert_eq!(lcm(i128::MAX, i128::MAX - 1), None);
+}
+
+#[test]
+fn extended_gcd_finds_bezout_coefficients() {
+    for (a, b) in [(240, 46), (-240, 46), (17, 0), (0, -5), (101, 103)] {
+        let (g, x, y) = extended_gcd(a, b);
+
+        assert_eq!(g, gcd(a, b));
+        assert_eq!(a * x + b * y, g);
+    }
+}
+
+#[test]
+fn mod_inverse_only_exists_for_coprime_numbers() {
+    assert_eq!(mod_inverse(3, 11), Some(4));
+    assert_eq!(mod_inverse(-3, 11), Some(7));
+    assert_eq!(mod_inverse(101, 103), Some(51));
+    assert_eq!(mod_inverse(6, 9), None);
+}
+
+#[test]
+fn chinese_remainder_combines_congruences() {
+    assert_eq!(
+        chinese_remainder(&[(2, 3), (3, 5), (2, 7)]),
+        Some((23, 105))
+    );
+    assert_eq!(chinese_remainder(&[(2, 4), (4, 6)]), Some((10, 12)));
+    assert_eq!(chinese_remainder(&[(1, 4), (2, 6)]), None);
+    assert_eq!(chinese_remainder(&[]), Some((0, 1)));
+    assert_eq!(
+        chinese_remainder(&[(1, i128::MAX), (2, i128::MAX - 1)]),
+        None
+    );
+}
+
+#[test]
+fn linear_diophantine_solutions_are_all_valid() {
+    let solutions = solve_linear_diophantine(94, 22, 8400).unwrap();
+    for k in -3..3 {
+        let x = solutions.x + k * solutions.x_step;
+        let y = solutions.y - k * solutions.y_step;
+        assert_eq!(94 * x + 22 * y, 8400);
+    }
+
+    assert_eq!(solve_linear_diophantine(4, 6, 7), None);
+}
+
+#[test]
+fn solve_2x2_needs_a_whole_unique_solution() {
+    assert_eq!(solve_2x2([94, 22, 8400], [34, 67, 5400]), Some((80, 40)));
+    assert_eq!(solve_2x2([26, 67, 12748], [66, 21, 12176]), None);
+    assert_eq!(solve_2x2([1, 2, 3], [2, 4, 6]), None);
+}
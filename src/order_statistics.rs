@@ -0,0 +1,311 @@
+use std::cmp::Ordering;
+
+/// A sorted multiset of numbers that answers "the k-th smallest" and "how many
+/// are smaller" in logarithmic time. It is a treap, each node holds one
+/// distinct value with its count and the size of its subtree.
+#[derive(Debug, Clone)]
+pub struct OrderStatisticTree {
+    root: Option<Box<Node>>,
+    seed: u64,
+}
+
+#[derive(Debug, Clone)]
+struct Node {
+    value: i64,
+    count: usize,
+    size: usize,
+    priority: u64,
+    left: Option<Box<Node>>,
+    right: Option<Box<Node>>,
+}
+
+fn size(node: &Option<Box<Node>>) -> usize {
+    return node.as_ref().map_or(0, |node| node.size);
+}
+
+impl Node {
+    fn update_size(&mut self) {
+        self.size = size(&self.left) + self.count + size(&self.right);
+    }
+}
+
+fn rotate_right(mut node: Box<Node>) -> Box<Node> {
+    let mut left = node.left.take().unwrap();
+    node.left = left.right.take();
+    node.update_size();
+    left.right = Some(node);
+    left.update_size();
+    return left;
+}
+
+fn rotate_left(mut node: Box<Node>) -> Box<Node> {
+    let mut right = node.right.take().unwrap();
+    node.right = right.left.take();
+    node.update_size();
+    right.left = Some(node);
+    right.update_size();
+    return right;
+}
+
+fn insert(node: Option<Box<Node>>, value: i64, priority: u64) -> Box<Node> {
+    let Some(mut node) = node else {
+        return Box::new(Node {
+            value,
+            count: 1,
+            size: 1,
+            priority,
+            left: None,
+            right: None,
+        });
+    };
+
+    match value.cmp(&node.value) {
+        Ordering::Equal => node.count += 1,
+        Ordering::Less => {
+            let left = insert(node.left.take(), value, priority);
+            let rotate = left.priority > node.priority;
+            node.left = Some(left);
+            if rotate {
+                node.update_size();
+                return rotate_right(node);
+            }
+        }
+        Ordering::Greater => {
+            let right = insert(node.right.take(), value, priority);
+            let rotate = right.priority > node.priority;
+            node.right = Some(right);
+            if rotate {
+                node.update_size();
+                return rotate_left(node);
+            }
+        }
+    }
+
+    node.update_size();
+    return node;
+}
+
+fn remove(node: Option<Box<Node>>, value: i64) -> (Option<Box<Node>>, bool) {
+    let Some(mut node) = node else {
+        return (None, false);
+    };
+
+    let removed = match value.cmp(&node.value) {
+        Ordering::Less => {
+            let (left, removed) = remove(node.left.take(), value);
+            node.left = left;
+            removed
+        }
+        Ordering::Greater => {
+            let (right, removed) = remove(node.right.take(), value);
+            node.right = right;
+            removed
+        }
+        Ordering::Equal if node.count > 1 => {
+            node.count -= 1;
+            true
+        }
+        Ordering::Equal => return (merge(node.left.take(), node.right.take()), true),
+    };
+
+    node.update_size();
+    return (Some(node), removed);
+}
+
+/// Joins two treaps where everything in `left` is smaller than everything in
+/// `right`, keeping the higher priority on top.
+fn merge(left: Option<Box<Node>>, right: Option<Box<Node>>) -> Option<Box<Node>> {
+    match (left, right) {
+        (None, right) => return right,
+        (left, None) => return left,
+        (Some(mut left), Some(mut right)) => {
+            if left.priority > right.priority {
+                left.right = merge(left.right.take(), Some(right));
+                left.update_size();
+                return Some(left);
+            }
+
+            right.left = merge(Some(left), right.left.take());
+            right.update_size();
+            return Some(right);
+        }
+    }
+}
+
+impl OrderStatisticTree {
+    pub fn new() -> Self {
+        return OrderStatisticTree {
+            root: None,
+            seed: 0x9e37_79b9_7f4a_7c15,
+        };
+    }
+
+    pub fn len(&self) -> usize {
+        return size(&self.root);
+    }
+
+    pub fn is_empty(&self) -> bool {
+        return self.root.is_none();
+    }
+
+    pub fn insert(&mut self, value: i64) {
+        // xorshift, the priorities only have to look random to keep it balanced
+        self.seed ^= self.seed << 13;
+        self.seed ^= self.seed >> 7;
+        self.seed ^= self.seed << 17;
+
+        self.root = Some(insert(self.root.take(), value, self.seed));
+    }
+
+    /// Removes one copy of `value`, `false` when there was none.
+    pub fn remove(&mut self, value: i64) -> bool {
+        let (root, removed) = remove(self.root.take(), value);
+        self.root = root;
+        return removed;
+    }
+
+    /// The `index`-th smallest value, counting from 0.
+    pub fn select(&self, mut index: usize) -> Option<i64> {
+        let mut node = self.root.as_ref();
+        while let Some(current) = node {
+            let left_size = size(&current.left);
+            if index < left_size {
+                node = current.left.as_ref();
+            } else if index < left_size + current.count {
+                return Some(current.value);
+            } else {
+                index -= left_size + current.count;
+                node = current.right.as_ref();
+            }
+        }
+
+        return None;
+    }
+
+    /// How many values are smaller than `value`.
+    pub fn rank(&self, value: i64) -> usize {
+        let mut rank = 0;
+        let mut node = self.root.as_ref();
+        while let Some(current) = node {
+            match value.cmp(&current.value) {
+                Ordering::Less => node = current.left.as_ref(),
+                Ordering::Equal => return rank + size(&current.left),
+                Ordering::Greater => {
+                    rank += size(&current.left) + current.count;
+                    node = current.right.as_ref();
+                }
+            }
+        }
+
+        return rank;
+    }
+
+    pub fn count(&self, value: i64) -> usize {
+        let mut node = self.root.as_ref();
+        while let Some(current) = node {
+            match value.cmp(&current.value) {
+                Ordering::Less => node = current.left.as_ref(),
+                Ordering::Equal => return current.count,
+                Ordering::Greater => node = current.right.as_ref(),
+            }
+        }
+
+        return 0;
+    }
+
+    /// Each distinct value with how often it was inserted, smallest first.
+    pub fn runs(&self) -> Runs<'_> {
+        let mut runs = Runs { stack: vec![] };
+        runs.push_left_spine(self.root.as_deref());
+        return runs;
+    }
+}
+
+impl Default for OrderStatisticTree {
+    fn default() -> Self {
+        return OrderStatisticTree::new();
+    }
+}
+
+pub struct Runs<'a> {
+    stack: Vec<&'a Node>,
+}
+
+impl<'a> Runs<'a> {
+    fn push_left_spine(&mut self, mut node: Option<&'a Node>) {
+        while let Some(current) = node {
+            self.stack.push(current);
+            node = current.left.as_deref();
+        }
+    }
+}
+
+impl<'a> Iterator for Runs<'a> {
+    type Item = (i64, usize);
+
+    fn next(&mut self) -> Option<(i64, usize)> {
+        let node = self.stack.pop()?;
+        self.push_left_spine(node.right.as_deref());
+
+        return Some((node.value, node.count));
+    }
+}
+
+#[test]
+fn select_and_rank_match_a_sorted_vector() {
+    let mut tree = OrderStatisticTree::new();
+    let mut sorted = vec![];
+    for index in 0..500 {
+        let value = (index * 7919 % 211) as i64 - 100;
+        tree.insert(value);
+        sorted.push(value);
+    }
+    sorted.sort();
+
+    assert_eq!(tree.len(), 500);
+    for (index, value) in sorted.iter().enumerate() {
+        assert_eq!(tree.select(index), Some(*value));
+        assert_eq!(
+            tree.rank(*value),
+            sorted.partition_point(|other| other < value)
+        );
+    }
+    assert_eq!(tree.select(500), None);
+    assert_eq!(
+        tree.count(sorted[0]),
+        sorted.iter().filter(|value| **value == sorted[0]).count()
+    );
+
+    let expanded = tree
+        .runs()
+        .flat_map(|(value, count)| std::iter::repeat(value).take(count))
+        .collect::<Vec<i64>>();
+    assert_eq!(expanded, sorted);
+}
+
+#[test]
+fn removals_match_a_sorted_vector() {
+    let mut tree = OrderStatisticTree::new();
+    let mut sorted = vec![];
+    for index in 0..300 {
+        let value = (index * 7919 % 101) as i64;
+        tree.insert(value);
+        sorted.push(value);
+    }
+    sorted.sort();
+
+    for index in 0..200 {
+        let value = (index * 31 % 101) as i64;
+        let position = sorted.iter().position(|other| *other == value);
+        assert_eq!(tree.remove(value), position.is_some());
+        if let Some(position) = position {
+            sorted.remove(position);
+        }
+    }
+    assert!(!tree.remove(1000));
+
+    assert_eq!(tree.len(), sorted.len());
+    for (index, value) in sorted.iter().enumerate() {
+        assert_eq!(tree.select(index), Some(*value));
+    }
+}
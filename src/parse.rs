@@ -0,0 +1,289 @@
+use std::{fmt, str::FromStr, sync::OnceLock};
+
+use regex::Regex;
+
+/// Where parsing went wrong. Lines and columns start at 1 so they match what
+/// an editor shows for the input file.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParseError {
+    pub line: usize,
+    pub column: usize,
+    pub message: String,
+}
+
+impl ParseError {
+    pub fn new(line: usize, column: usize, message: impl Into<String>) -> Self {
+        return ParseError {
+            line,
+            column,
+            message: message.into(),
+        };
+    }
+}
+
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "line {}, column {}: {}",
+            self.line, self.column, self.message
+        )
+    }
+}
+
+impl std::error::Error for ParseError {}
+
+/// Some text together with where it starts in the whole input, so errors found
+/// while parsing a section or part of a line still point at the right place.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Line<'a> {
+    pub number: usize,
+    pub column: usize,
+    pub text: &'a str,
+}
+
+impl<'a> Line<'a> {
+    pub fn new(number: usize, text: &'a str) -> Self {
+        return Line {
+            number,
+            column: 1,
+            text,
+        };
+    }
+
+    /// `column` is relative to this line's text.
+    pub fn error(&self, column: usize, message: impl Into<String>) -> ParseError {
+        return ParseError::new(self.number, self.column + column - 1, message);
+    }
+
+    /// Narrows the line down to `part`, which must be a slice of its text.
+    pub fn sub(&self, part: &'a str) -> Line<'a> {
+        return Line {
+            number: self.number,
+            column: self.column_of(part),
+            text: part,
+        };
+    }
+
+    /// Every integer in the line, including a leading `-`, ignoring whatever
+    /// text surrounds them.
+    pub fn integers<T: FromStr>(&self) -> Result<Vec<T>, ParseError> {
+        static INTEGER_REGEX: OnceLock<Regex> = OnceLock::new();
+        let integer_regex = INTEGER_REGEX.get_or_init(|| Regex::new(r"-?\d+").unwrap());
+
+        return integer_regex
+            .find_iter(self.text)
+            .map(|integer| {
+                integer.as_str().parse::<T>().map_err(|_| {
+                    self.sub(integer.as_str())
+                        .error(1, format!("{:?} is out of range", integer.as_str()))
+                })
+            })
+            .collect();
+    }
+
+    /// Splits `key<separator>value`, trimming whitespace around both halves.
+    pub fn key_value(&self, separator: &str) -> Result<(Line<'a>, Line<'a>), ParseError> {
+        let position = self
+            .text
+            .find(separator)
+            .ok_or_else(|| self.error(1, format!("expected {:?}", separator)))?;
+
+        let (key, value) = self.text.split_at(position);
+        return Ok((
+            self.sub(key.trim()),
+            self.sub(value[separator.len()..].trim()),
+        ));
+    }
+
+    pub fn separated<T: FromStr>(&self, separator: char) -> Result<Vec<T>, ParseError> {
+        return self.items(self.text.split(separator).map(str::trim));
+    }
+
+    pub fn whitespace_separated<T: FromStr>(&self) -> Result<Vec<T>, ParseError> {
+        return self.items(self.text.split_whitespace());
+    }
+
+    fn items<T: FromStr>(
+        &self,
+        items: impl Iterator<Item = &'a str>,
+    ) -> Result<Vec<T>, ParseError> {
+        return items
+            .map(|item| {
+                item.parse::<T>()
+                    .map_err(|_| self.sub(item).error(1, format!("unexpected {:?}", item)))
+            })
+            .collect();
+    }
+
+    /// Column in the input file of a slice of this line's text.
+    fn column_of(&self, part: &str) -> usize {
+        let offset = part.as_ptr() as usize - self.text.as_ptr() as usize;
+        assert!(offset <= self.text.len(), "not part of this line");
+
+        return self.column + self.text[..offset].chars().count();
+    }
+}
+
+/// The non empty lines of `text`. Blank lines are skipped but still counted,
+/// and `\r\n` line endings are accepted.
+pub fn lines(text: &str) -> Vec<Line<'_>> {
+    return text
+        .lines()
+        .enumerate()
+        .map(|(index, line)| Line::new(index + 1, line.trim_end_matches('\r')))
+        .filter(|line| !line.text.trim().is_empty())
+        .collect();
+}
+
+/// Every integer in `text`, across all of its lines.
+pub fn integers<T: FromStr>(text: &str) -> Result<Vec<T>, ParseError> {
+    let mut integers = vec![];
+    for line in lines(text) {
+        integers.extend(line.integers()?);
+    }
+    return Ok(integers);
+}
+
+/// Splits the input on blank lines. Leading, trailing and repeated blank lines
+/// never produce empty sections.
+pub fn sections(text: &str) -> Vec<Vec<Line<'_>>> {
+    let mut sections = vec![];
+    let mut current = vec![];
+
+    for (index, line) in text.lines().enumerate() {
+        let line = line.trim_end_matches('\r');
+        if line.trim().is_empty() {
+            if !current.is_empty() {
+                sections.push(current);
+                current = vec![];
+            }
+            continue;
+        }
+
+        current.push(Line::new(index + 1, line));
+    }
+
+    if !current.is_empty() {
+        sections.push(current);
+    }
+
+    return sections;
+}
+
+/// A rectangular grid of characters. Rows of different lengths are an error,
+/// since every grid solver indexes with the first row's width.
+pub fn char_grid(text: &str) -> Result<Vec<Vec<char>>, ParseError> {
+    return grid_of_lines(&lines(text));
+}
+
+pub fn grid_of_lines(lines: &[Line]) -> Result<Vec<Vec<char>>, ParseError> {
+    let width = lines
+        .first()
+        .map(|line| line.text.chars().count())
+        .unwrap_or(0);
+
+    return lines
+        .iter()
+        .map(|line| {
+            let row = line.text.chars().collect::<Vec<char>>();
+            if row.len() != width {
+                return Err(line.error(
+                    row.len().min(width) + 1,
+                    format!("row is {} wide, expected {}", row.len(), width),
+                ));
+            }
+            return Ok(row);
+        })
+        .collect();
+}
+
+/// A rectangular grid of single digits, like a height map.
+pub fn digit_grid(text: &str) -> Result<Vec<Vec<u32>>, ParseError> {
+    let lines = lines(text);
+    let grid = grid_of_lines(&lines)?;
+
+    return grid
+        .iter()
+        .zip(&lines)
+        .map(|(row, line)| {
+            row.iter()
+                .enumerate()
+                .map(|(index, character)| {
+                    character.to_digit(10).ok_or_else(|| {
+                        line.error(index + 1, format!("{:?} is not a digit", character))
+                    })
+                })
+                .collect()
+        })
+        .collect();
+}
+
+#[test]
+fn integers_include_signs_and_skip_text() {
+    let line = Line::new(1, "p=0,4 v=3,-3");
+
+    assert_eq!(line.integers::<isize>(), Ok(vec![0, 4, 3, -3]));
+}
+
+#[test]
+fn integer_overflow_points_at_the_number() {
+    let line = Line::new(3, "Button A: X+94, Y+999");
+
+    assert_eq!(
+        line.integers::<u8>(),
+        Err(ParseError::new(3, 19, "\"999\" is out of range"))
+    );
+}
+
+#[test]
+fn sections_keep_line_numbers_and_ignore_extra_blank_lines() {
+    let text = "\n47|53\n97|13\n\n\n75,47,61\n\n";
+
+    let sections = sections(text);
+
+    assert_eq!(sections.len(), 2);
+    assert_eq!(sections[0][0], Line::new(2, "47|53"));
+    assert_eq!(sections[1][0], Line::new(6, "75,47,61"));
+}
+
+#[test]
+fn key_value_and_lists() {
+    let line = Line::new(1, "190: 10 19");
+
+    let (key, value) = line.key_value(":").unwrap();
+
+    assert_eq!(key.text, "190");
+    assert_eq!(value.whitespace_separated::<usize>(), Ok(vec![10, 19]));
+    assert_eq!(
+        value.separated::<usize>(','),
+        Err(ParseError::new(1, 6, "unexpected \"10 19\""))
+    );
+}
+
+#[test]
+fn list_errors_point_at_the_bad_item() {
+    let line = Line::new(7, "75,47,x1,29");
+
+    assert_eq!(
+        line.separated::<i32>(','),
+        Err(ParseError::new(7, 7, "unexpected \"x1\""))
+    );
+}
+
+#[test]
+fn ragged_grids_are_rejected() {
+    assert_eq!(
+        char_grid("..#\n.#\n"),
+        Err(ParseError::new(2, 3, "row is 2 wide, expected 3"))
+    );
+    assert_eq!(char_grid("..#\n.#.\n\n").unwrap().len(), 2);
+}
+
+#[test]
+fn digit_grid_reports_non_digits() {
+    assert_eq!(
+        digit_grid("0123\n1.34"),
+        Err(ParseError::new(2, 2, "'.' is not a digit"))
+    );
+}
@@ -0,0 +1,347 @@
+use std::{
+    collections::BTreeMap,
+    fs, io,
+    io::Write,
+    path::Path,
+    process::Command,
+    time::{Duration, SystemTime, UNIX_EPOCH},
+};
+
+use crate::{
+    registry::{puzzle_info, Solution},
+    runner::{run_solution, Outcome, RunOptions},
+};
+
+pub const DEFAULT_HISTORY_PATH: &str = ".cache/perf_history.tsv";
+pub const DEFAULT_SAMPLES: usize = 5;
+pub const DEFAULT_THRESHOLD_PERCENT: f64 = 10.0;
+
+/// The median time of one part from one benchmark run. All records written by
+/// the same run share its timestamp.
+#[derive(Debug, Clone, PartialEq)]
+pub struct PerfRecord {
+    pub timestamp: u64,
+    pub commit: String,
+    pub machine: String,
+    pub year: usize,
+    pub day: usize,
+    pub part: usize,
+    pub median: Duration,
+    pub samples: usize,
+}
+
+impl PerfRecord {
+    fn to_line(&self) -> String {
+        return format!(
+            "{}\t{}\t{}\t{}\t{}\t{}\t{}\t{}\n",
+            self.timestamp,
+            self.commit,
+            self.machine,
+            self.year,
+            self.day,
+            self.part,
+            self.median.as_nanos(),
+            self.samples
+        );
+    }
+
+    fn from_line(line: &str) -> Option<PerfRecord> {
+        let parts = line.split('\t').collect::<Vec<&str>>();
+        let [timestamp, commit, machine, year, day, part, median, samples] = parts[..] else {
+            return None;
+        };
+
+        return Some(PerfRecord {
+            timestamp: timestamp.parse().ok()?,
+            commit: commit.to_string(),
+            machine: machine.to_string(),
+            year: year.parse().ok()?,
+            day: day.parse().ok()?,
+            part: part.parse().ok()?,
+            median: Duration::from_nanos(median.parse().ok()?),
+            samples: samples.parse().ok()?,
+        });
+    }
+}
+
+/// A missing history file is an empty history, lines that don't parse are
+/// skipped.
+pub fn load_history(path: &Path) -> Vec<PerfRecord> {
+    return fs::read_to_string(path)
+        .unwrap_or_default()
+        .lines()
+        .filter_map(PerfRecord::from_line)
+        .collect();
+}
+
+pub fn append_history(path: &Path, records: &[PerfRecord]) -> io::Result<()> {
+    if let Some(directory) = path.parent() {
+        fs::create_dir_all(directory)?;
+    }
+
+    let mut file = fs::OpenOptions::new()
+        .create(true)
+        .append(true)
+        .open(path)?;
+    for record in records {
+        file.write_all(record.to_line().as_bytes())?;
+    }
+    return Ok(());
+}
+
+/// The short hash of `HEAD`, with `-dirty` added when the tree has changes, so
+/// runs of uncommitted work aren't mistaken for the commit.
+pub fn current_commit() -> String {
+    let git = |arguments: &[&str]| {
+        Command::new("git")
+            .args(arguments)
+            .output()
+            .ok()
+            .filter(|output| output.status.success())
+            .map(|output| String::from_utf8_lossy(&output.stdout).trim().to_string())
+    };
+
+    let Some(commit) = git(&["rev-parse", "--short", "HEAD"]) else {
+        return "unknown".to_string();
+    };
+    if git(&["status", "--porcelain", "--untracked-files=no"])
+        .is_some_and(|status| !status.is_empty())
+    {
+        return format!("{commit}-dirty");
+    }
+    return commit;
+}
+
+pub fn current_machine() -> String {
+    return fs::read_to_string("/etc/hostname")
+        .ok()
+        .or_else(|| std::env::var("HOSTNAME").ok())
+        .map(|hostname| hostname.trim().to_string())
+        .filter(|hostname| !hostname.is_empty())
+        .unwrap_or("unknown".to_string());
+}
+
+/// Runs every solution `samples` times without the answer cache and records
+/// the median. Parts that don't finish every time are left out.
+pub fn benchmark(solutions: &[Solution], options: &RunOptions, samples: usize) -> Vec<PerfRecord> {
+    let options = RunOptions {
+        report_allocations: false,
+        timeout: options.timeout,
+        use_cache: false,
+        validate_input: options.validate_input,
+        input_path: options.input_path.clone(),
+        explain: false,
+    };
+    let timestamp = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map(|since_epoch| since_epoch.as_secs())
+        .unwrap_or_default();
+    let commit = current_commit();
+    let machine = current_machine();
+
+    let mut records = vec![];
+    for solution in solutions {
+        let mut times = vec![];
+        for _ in 0..samples {
+            let result = run_solution(solution, &options);
+            if result.outcome != Outcome::Finished || result.unreliable {
+                break;
+            }
+            times.push(result.elapsed);
+        }
+        if times.len() < samples {
+            continue;
+        }
+
+        times.sort();
+        records.push(PerfRecord {
+            timestamp,
+            commit: commit.clone(),
+            machine: machine.clone(),
+            year: solution.year,
+            day: solution.day,
+            part: solution.part,
+            median: times[times.len() / 2],
+            samples,
+        });
+    }
+
+    return records;
+}
+
+/// One block character per value, scaled between the smallest and the largest.
+pub fn sparkline(values: &[f64]) -> String {
+    let blocks = ['▁', '▂', '▃', '▄', '▅', '▆', '▇', '█'];
+    let minimum = values.iter().copied().fold(f64::INFINITY, f64::min);
+    let maximum = values.iter().copied().fold(f64::NEG_INFINITY, f64::max);
+
+    return values
+        .iter()
+        .map(|value| {
+            if maximum <= minimum {
+                return blocks[0];
+            }
+            let scaled = (value - minimum) / (maximum - minimum) * (blocks.len() - 1) as f64;
+            return blocks[scaled.round() as usize];
+        })
+        .collect();
+}
+
+/// How one part's median moved between the previous and the latest run on a
+/// machine.
+#[derive(Debug, Clone, PartialEq)]
+pub struct PerfTrend {
+    pub year: usize,
+    pub day: usize,
+    pub part: usize,
+    /// Every recorded median, oldest first.
+    pub medians: Vec<Duration>,
+    pub change_percent: Option<f64>,
+    pub is_regression: bool,
+}
+
+/// Only compares runs from `machine`, timings from different hardware say
+/// nothing about the code.
+pub fn perf_trends(
+    records: &[PerfRecord],
+    machine: &str,
+    threshold_percent: f64,
+) -> Vec<PerfTrend> {
+    let mut by_part = BTreeMap::<(usize, usize, usize), Vec<&PerfRecord>>::new();
+    for record in records.iter().filter(|record| record.machine == machine) {
+        by_part
+            .entry((record.year, record.day, record.part))
+            .or_default()
+            .push(record);
+    }
+
+    return by_part
+        .into_iter()
+        .map(|((year, day, part), mut part_records)| {
+            part_records.sort_by_key(|record| record.timestamp);
+            let medians = part_records
+                .iter()
+                .map(|record| record.median)
+                .collect::<Vec<Duration>>();
+
+            let change_percent = match medians[..] {
+                [.., previous, latest] if !previous.is_zero() => Some(
+                    (latest.as_secs_f64() - previous.as_secs_f64()) / previous.as_secs_f64()
+                        * 100.0,
+                ),
+                _ => None,
+            };
+
+            return PerfTrend {
+                year,
+                day,
+                part,
+                medians,
+                change_percent,
+                is_regression: change_percent.is_some_and(|change| change > threshold_percent),
+            };
+        })
+        .collect();
+}
+
+pub fn format_perf_report(trends: &[PerfTrend], threshold_percent: f64) -> String {
+    let mut output = format!(
+        "{:>4} {:>4} {:>4} {:>12} {:>12} {:>8}  {:<12} {}\n",
+        "year", "day", "part", "previous", "latest", "change", "history", "puzzle"
+    );
+
+    for trend in trends {
+        let previous = match trend.medians[..] {
+            [.., previous, _] => format!("{:.2?}", previous),
+            _ => "-".to_string(),
+        };
+        let history = trend
+            .medians
+            .iter()
+            .map(|median| median.as_secs_f64())
+            .collect::<Vec<f64>>();
+        let title = puzzle_info(trend.year, trend.day)
+            .map(|puzzle| puzzle.label())
+            .unwrap_or_default();
+
+        output += &format!(
+            "{:>4} {:>4} {:>4} {:>12} {:>12} {:>8}  {:<12} {}{}\n",
+            trend.year,
+            trend.day,
+            trend.part,
+            previous,
+            format!("{:.2?}", trend.medians.last().unwrap()),
+            trend
+                .change_percent
+                .map(|change| format!("{change:+.1}%"))
+                .unwrap_or("-".to_string()),
+            sparkline(&history),
+            title,
+            if trend.is_regression {
+                "  REGRESSED"
+            } else {
+                ""
+            }
+        );
+    }
+
+    let regressions = trends.iter().filter(|trend| trend.is_regression).count();
+    output += &format!(
+        "\n{regressions} part(s) slower than the previous run by more than {threshold_percent}%\n"
+    );
+    return output;
+}
+
+#[test]
+fn records_round_trip_through_lines() {
+    let record = PerfRecord {
+        timestamp: 1734000000,
+        commit: "916a768-dirty".to_string(),
+        machine: "laptop".to_string(),
+        year: 2024,
+        day: 6,
+        part: 2,
+        median: Duration::from_micros(1500),
+        samples: 5,
+    };
+
+    assert_eq!(
+        PerfRecord::from_line(record.to_line().trim_end()),
+        Some(record)
+    );
+    assert_eq!(PerfRecord::from_line("not\ta record"), None);
+}
+
+#[test]
+fn sparkline_spans_smallest_to_largest() {
+    assert_eq!(sparkline(&[1.0, 8.0, 4.5]), "▁█▅");
+    assert_eq!(sparkline(&[3.0, 3.0]), "▁▁");
+}
+
+#[test]
+fn regressions_compare_latest_with_previous_run_on_same_machine() {
+    let record = |timestamp: u64, machine: &str, part: usize, millis: u64| PerfRecord {
+        timestamp,
+        commit: "abc".to_string(),
+        machine: machine.to_string(),
+        year: 2024,
+        day: 1,
+        part,
+        median: Duration::from_millis(millis),
+        samples: 3,
+    };
+    let records = vec![
+        record(1, "laptop", 1, 100),
+        record(1, "laptop", 2, 100),
+        record(2, "server", 1, 500),
+        record(3, "laptop", 1, 105),
+        record(3, "laptop", 2, 130),
+    ];
+
+    let trends = perf_trends(&records, "laptop", 10.0);
+
+    assert_eq!(trends.len(), 2);
+    assert!(!trends[0].is_regression);
+    assert!(trends[1].is_regression);
+    assert_eq!(trends[1].medians.len(), 2);
+}
@@ -0,0 +1,161 @@
+use crate::{
+    context::{Cancelled, Context},
+    year2024,
+};
+
+pub type SolverFn = fn(&Context) -> Result<String, Cancelled>;
+
+#[derive(Clone, Copy)]
+pub struct Solution {
+    pub year: usize,
+    pub day: usize,
+    pub part: usize,
+    pub run: SolverFn,
+}
+
+impl Solution {
+    pub(crate) fn new(year: usize, day: usize, part: usize, run: SolverFn) -> Self {
+        return Solution {
+            year,
+            day,
+            part,
+            run,
+        };
+    }
+}
+
+/// Every event year with solutions, oldest first.
+pub const YEARS: [usize; 1] = [year2024::YEAR];
+
+/// The year picked when none is given.
+pub fn latest_year() -> usize {
+    return *YEARS.last().unwrap();
+}
+
+pub fn solutions() -> Vec<Solution> {
+    return year2024::solutions();
+}
+
+/// What each module actually solves.
+#[derive(Debug, Clone, Copy)]
+pub struct PuzzleInfo {
+    pub year: usize,
+    pub module: usize,
+    pub calendar_day: usize,
+    pub title: &'static str,
+    pub input_shape: &'static str,
+}
+
+impl PuzzleInfo {
+    pub(crate) const fn new(
+        year: usize,
+        module: usize,
+        calendar_day: usize,
+        title: &'static str,
+        input_shape: &'static str,
+    ) -> Self {
+        return PuzzleInfo {
+            year,
+            module,
+            calendar_day,
+            title,
+            input_shape,
+        };
+    }
+
+    pub fn implemented_parts(&self) -> Vec<usize> {
+        return find_solutions(self.year, self.module, None)
+            .iter()
+            .map(|solution| solution.part)
+            .collect();
+    }
+
+    /// Short label for reports, e.g. `Monkey Market, calendar day 22`.
+    pub fn label(&self) -> String {
+        if self.calendar_day == self.module {
+            return self.title.to_string();
+        }
+        return format!("{}, calendar day {}", self.title, self.calendar_day);
+    }
+}
+
+pub fn puzzles() -> Vec<PuzzleInfo> {
+    return year2024::PUZZLES.to_vec();
+}
+
+pub fn puzzle_info(year: usize, module: usize) -> Option<PuzzleInfo> {
+    return puzzles()
+        .into_iter()
+        .find(|puzzle| puzzle.year == year && puzzle.module == module);
+}
+
+/// The `list` command's table of every module and what it solves, for one
+/// year or all of them.
+pub fn format_puzzle_list(year: Option<usize>) -> String {
+    let mut output = format!(
+        "{:>4} {:>6} {:>8}  {:<24} {:<6} {}\n",
+        "year", "module", "calendar", "title", "parts", "input"
+    );
+
+    for puzzle in puzzles() {
+        if year.is_some_and(|year| year != puzzle.year) {
+            continue;
+        }
+
+        let parts = puzzle
+            .implemented_parts()
+            .iter()
+            .map(|part| part.to_string())
+            .collect::<Vec<String>>()
+            .join(",");
+
+        output += &format!(
+            "{:>4} {:>6} {:>8}  {:<24} {:<6} {}\n",
+            puzzle.year,
+            puzzle.module,
+            puzzle.calendar_day,
+            puzzle.title,
+            parts,
+            puzzle.input_shape
+        );
+    }
+
+    return output;
+}
+
+pub fn find_solutions(year: usize, day: usize, part: Option<usize>) -> Vec<Solution> {
+    return solutions()
+        .into_iter()
+        .filter(|solution| solution.year == year && solution.day == day)
+        .filter(|solution| part.is_none() || part == Some(solution.part))
+        .collect();
+}
+
+pub fn find_year_solutions(year: usize) -> Vec<Solution> {
+    return solutions()
+        .into_iter()
+        .filter(|solution| solution.year == year)
+        .collect();
+}
+
+#[test]
+fn every_registered_day_has_metadata() {
+    for solution in solutions() {
+        assert!(
+            puzzle_info(solution.year, solution.day).is_some(),
+            "{} day {}",
+            solution.year,
+            solution.day
+        );
+    }
+}
+
+#[test]
+fn modules_past_day_20_are_labelled_with_their_calendar_day() {
+    assert_eq!(puzzle_info(2024, 12).unwrap().label(), "Garden Groups");
+    assert_eq!(
+        puzzle_info(2024, 21).unwrap().label(),
+        "Monkey Market, calendar day 22"
+    );
+    assert_eq!(puzzle_info(2024, 22).unwrap().implemented_parts(), vec![1]);
+}
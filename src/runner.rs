@@ -0,0 +1,380 @@
+use std::{
+    path::{Path, PathBuf},
+    sync::{
+        atomic::{AtomicU8, AtomicUsize, Ordering},
+        mpsc, Arc,
+    },
+    thread,
+    time::{Duration, Instant},
+};
+
+use crate::{
+    alloc_stats::{self, format_bytes, AllocationReport},
+    cache::{AnswerCache, CacheKey, DEFAULT_CACHE_PATH},
+    context::Context,
+    explain::Explanation,
+    input,
+    metrics::{self, format_counters, Counters},
+    registry::{puzzle_info, Solution},
+    validate::validate_input,
+};
+
+pub struct RunOptions {
+    pub report_allocations: bool,
+    pub timeout: Option<Duration>,
+    pub use_cache: bool,
+    /// Check the input's shape first and refuse to solve it if that fails.
+    pub validate_input: bool,
+    /// Solve this file instead of the input committed next to the day.
+    pub input_path: Option<PathBuf>,
+    /// Collect the solvers' walkthroughs. Cached answers are skipped, they have
+    /// nothing to explain.
+    pub explain: bool,
+}
+
+impl Default for RunOptions {
+    fn default() -> Self {
+        return RunOptions {
+            report_allocations: false,
+            timeout: None,
+            use_cache: true,
+            validate_input: true,
+            input_path: None,
+            explain: false,
+        };
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Outcome {
+    Finished,
+    /// The solver noticed the cancellation itself and returned early.
+    Cancelled,
+    /// The solver ignored the cancellation and was still running at the deadline.
+    TimedOut,
+    Panicked,
+    /// The input failed validation, so the solver never ran.
+    InvalidInput,
+}
+
+pub struct RunResult {
+    pub solution: Solution,
+    pub outcome: Outcome,
+    pub answer: Option<String>,
+    pub from_cache: bool,
+    pub elapsed: Duration,
+    pub allocations: AllocationReport,
+    /// What the solver counted through `metrics`, empty if it didn't finish.
+    pub counters: Counters,
+    pub explanations: Vec<Explanation>,
+    /// A solver that timed out earlier was still running, sharing the CPU and
+    /// the allocation counters, so the time and allocations are skewed.
+    pub unreliable: bool,
+}
+
+const GRACE_PERIOD: Duration = Duration::from_millis(500);
+
+/// Timed-out solvers that haven't returned yet. Threads can't be killed, so
+/// they keep running next to every part measured after them.
+static ABANDONED_SOLVERS: AtomicUsize = AtomicUsize::new(0);
+
+const SOLVER_RUNNING: u8 = 0;
+const SOLVER_DONE: u8 = 1;
+const SOLVER_ABANDONED: u8 = 2;
+
+/// How many timed-out solvers are still running.
+pub fn abandoned_solvers() -> usize {
+    return ABANDONED_SOLVERS.load(Ordering::SeqCst);
+}
+
+/// Runs a solver on its own thread so the runner can stop waiting for it once the
+/// timeout passes, even if the solver never checks its context.
+pub fn run_solution(solution: &Solution, options: &RunOptions) -> RunResult {
+    print_header(solution);
+
+    if options.validate_input {
+        // An unreadable input is left for the solver to report.
+        let input_path = options.input_path.as_deref();
+        if let Ok(text) = input::try_read_input(solution.year, solution.day, input_path) {
+            let problems = validate_input(solution.year, solution.day, &text);
+            if !problems.is_empty() {
+                println!("invalid input, not solving:");
+                for problem in problems {
+                    println!("  {problem}");
+                }
+
+                return RunResult {
+                    solution: *solution,
+                    outcome: Outcome::InvalidInput,
+                    answer: None,
+                    from_cache: false,
+                    elapsed: Duration::ZERO,
+                    allocations: AllocationReport::default(),
+                    counters: vec![],
+                    explanations: vec![],
+                    unreliable: false,
+                };
+            }
+        }
+    }
+
+    let mut context = Context::new().with_progress();
+    if let Some(timeout) = options.timeout {
+        context = context.with_timeout(timeout);
+    }
+    if options.explain {
+        context = context.with_explanations();
+    }
+    let context = Arc::new(context);
+
+    let (sender, receiver) = mpsc::channel();
+    let solver = solution.run;
+    let solver_context = Arc::clone(&context);
+    let input_path = options.input_path.clone();
+    let solver_state = Arc::new(AtomicU8::new(SOLVER_RUNNING));
+    let thread_state = Arc::clone(&solver_state);
+
+    let unreliable = abandoned_solvers() > 0;
+    let resident_reset = options.report_allocations && alloc_stats::reset_peak_resident();
+    let start = Instant::now();
+    thread::spawn(move || {
+        input::set_input_override(input_path);
+        let ((answer, allocations), counters) =
+            metrics::measure(|| alloc_stats::measure(|| solver(&solver_context)));
+        sender.send((answer, allocations, counters)).ok();
+        if thread_state.swap(SOLVER_DONE, Ordering::SeqCst) == SOLVER_ABANDONED {
+            ABANDONED_SOLVERS.fetch_sub(1, Ordering::SeqCst);
+        }
+    });
+
+    let received = match context.deadline() {
+        // Leave the solver a moment past the deadline to notice it and return cleanly.
+        Some(deadline) => {
+            receiver.recv_timeout(deadline.saturating_duration_since(Instant::now()) + GRACE_PERIOD)
+        }
+        None => receiver
+            .recv()
+            .map_err(|_| mpsc::RecvTimeoutError::Disconnected),
+    };
+    let elapsed = start.elapsed();
+
+    let (outcome, answer, mut allocations, counters) = match received {
+        Ok((Ok(answer), allocations, counters)) => {
+            (Outcome::Finished, Some(answer), allocations, counters)
+        }
+        Ok((Err(_), allocations, _)) => (Outcome::Cancelled, None, allocations, vec![]),
+        Err(mpsc::RecvTimeoutError::Timeout) => {
+            context.cancel();
+            // Counted first, so the thread can't take itself off before it's on.
+            ABANDONED_SOLVERS.fetch_add(1, Ordering::SeqCst);
+            if solver_state.swap(SOLVER_ABANDONED, Ordering::SeqCst) != SOLVER_RUNNING {
+                ABANDONED_SOLVERS.fetch_sub(1, Ordering::SeqCst);
+            }
+            (Outcome::TimedOut, None, AllocationReport::default(), vec![])
+        }
+        // The sender is dropped without sending when the solver thread panics.
+        Err(mpsc::RecvTimeoutError::Disconnected) => {
+            (Outcome::Panicked, None, AllocationReport::default(), vec![])
+        }
+    };
+
+    if resident_reset && outcome != Outcome::TimedOut {
+        allocations.peak_resident_bytes =
+            alloc_stats::peak_resident_kilobytes().map(|kilobytes| kilobytes * 1024);
+    }
+
+    match outcome {
+        Outcome::Finished => println!(
+            "answer: {} (finished in {:?})",
+            answer.as_deref().unwrap_or_default(),
+            elapsed
+        ),
+        Outcome::Cancelled => println!("cancelled after {:?}", elapsed),
+        Outcome::TimedOut => println!("timed out after {:?}, left running", elapsed),
+        Outcome::Panicked => println!("panicked after {:?}", elapsed),
+        Outcome::InvalidInput => unreachable!("invalid inputs are never solved"),
+    }
+
+    if unreliable && outcome != Outcome::TimedOut {
+        println!("unreliable: a timed-out solver was still running alongside");
+    }
+
+    if !counters.is_empty() {
+        println!("counters: {}", format_counters(&counters));
+    }
+
+    if options.report_allocations && outcome != Outcome::TimedOut {
+        println!(
+            "allocations: {}, allocated: {}, peak heap: {}, peak resident: {}",
+            allocations.allocations,
+            format_bytes(allocations.bytes_allocated),
+            format_bytes(allocations.peak_bytes),
+            format_resident(&allocations)
+        );
+    }
+
+    return RunResult {
+        solution: *solution,
+        outcome,
+        answer,
+        from_cache: false,
+        elapsed,
+        allocations,
+        counters,
+        explanations: context.take_explanations(),
+        unreliable,
+    };
+}
+
+/// Returns the cached answer when the input and solver are unchanged, otherwise
+/// runs the solver and remembers its answer if it finished.
+pub fn run_solution_with_cache(
+    solution: &Solution,
+    options: &RunOptions,
+    cache: &mut AnswerCache,
+) -> RunResult {
+    let key = CacheKey::for_solution(solution, options.input_path.as_deref());
+
+    if let Some(answer) = key.and_then(|key| cache.get(&key)) {
+        print_header(solution);
+        println!("answer: {} (cached)", answer);
+
+        return RunResult {
+            solution: *solution,
+            outcome: Outcome::Finished,
+            answer: Some(answer.clone()),
+            from_cache: true,
+            elapsed: Duration::ZERO,
+            allocations: AllocationReport::default(),
+            counters: vec![],
+            explanations: vec![],
+            unreliable: false,
+        };
+    }
+
+    let result = run_solution(solution, options);
+
+    if let (Some(key), Some(answer)) = (key, &result.answer) {
+        cache.insert(key, answer.clone());
+    }
+
+    return result;
+}
+
+fn print_header(solution: &Solution) {
+    match puzzle_info(solution.year, solution.day) {
+        Some(puzzle) => println!(
+            "--- {} day {} part {} ({}) ---",
+            solution.year,
+            solution.day,
+            solution.part,
+            puzzle.label()
+        ),
+        None => println!(
+            "--- {} day {} part {} ---",
+            solution.year, solution.day, solution.part
+        ),
+    }
+}
+
+pub fn run_solutions(solutions: &[Solution], options: &RunOptions) -> Vec<RunResult> {
+    let results: Vec<RunResult> = if options.use_cache && !options.explain {
+        let mut cache = AnswerCache::load(Path::new(DEFAULT_CACHE_PATH));
+        let results = solutions
+            .iter()
+            .map(|solution| run_solution_with_cache(solution, options, &mut cache))
+            .collect();
+
+        if let Err(error) = cache.save() {
+            eprintln!("could not save the answer cache: {error}");
+        }
+
+        results
+    } else {
+        solutions
+            .iter()
+            .map(|solution| run_solution(solution, options))
+            .collect()
+    };
+
+    if options.report_allocations && results.len() > 1 {
+        print_allocation_table(&results);
+    }
+
+    return results;
+}
+
+/// Prints every run sorted by bytes allocated, so the worst offenders are on top.
+fn print_allocation_table(results: &[RunResult]) {
+    let mut sorted = results.iter().collect::<Vec<&RunResult>>();
+    sorted.sort_by(|result_a, result_b| {
+        result_b
+            .allocations
+            .bytes_allocated
+            .cmp(&result_a.allocations.bytes_allocated)
+    });
+
+    println!();
+    println!(
+        "{:>4} {:>4} {:>5} {:>12} {:>12} {:>12} {:>13} {:>12}  puzzle",
+        "year", "day", "part", "allocations", "allocated", "peak heap", "peak resident", "time"
+    );
+    for result in sorted {
+        println!(
+            "{:>4} {:>4} {:>5} {:>12} {:>12} {:>12} {:>13} {:>12}  {}",
+            result.solution.year,
+            result.solution.day,
+            result.solution.part,
+            result.allocations.allocations,
+            format_bytes(result.allocations.bytes_allocated),
+            format_bytes(result.allocations.peak_bytes),
+            format_resident(&result.allocations),
+            format!(
+                "{:.2?}{}",
+                result.elapsed,
+                if result.unreliable { "*" } else { "" }
+            ),
+            puzzle_info(result.solution.year, result.solution.day)
+                .map(|puzzle| puzzle.label())
+                .unwrap_or_default()
+        );
+    }
+
+    if results.iter().any(|result| result.unreliable) {
+        println!("* measured while a timed-out solver was still running");
+    }
+}
+
+/// The per-part resident peak, or a dash where it couldn't be measured.
+fn format_resident(allocations: &AllocationReport) -> String {
+    return allocations
+        .peak_resident_bytes
+        .map(format_bytes)
+        .unwrap_or("-".to_string());
+}
+
+#[test]
+fn parts_measured_next_to_a_timed_out_solver_are_unreliable() {
+    let options = RunOptions {
+        timeout: Some(Duration::from_millis(10)),
+        use_cache: false,
+        validate_input: false,
+        ..RunOptions::default()
+    };
+    // Ignores its context, so it's still running after the grace period.
+    let stubborn = Solution::new(1999, 1, 1, |_| {
+        thread::sleep(GRACE_PERIOD * 2);
+        return Ok("late".to_string());
+    });
+    let quick = Solution::new(1999, 1, 2, |_| Ok("quick".to_string()));
+
+    assert_eq!(run_solution(&stubborn, &options).outcome, Outcome::TimedOut);
+    let alongside = run_solution(&quick, &options);
+    assert!(alongside.unreliable);
+
+    let deadline = Instant::now() + GRACE_PERIOD * 4;
+    while abandoned_solvers() > 0 && Instant::now() < deadline {
+        thread::sleep(Duration::from_millis(10));
+    }
+    assert!(!run_solution(&quick, &options).unreliable);
+}
@@ -0,0 +1,230 @@
+use std::{collections::HashMap, hash::Hash};
+
+use crate::metrics;
+
+/// A puzzle that advances in discrete ticks, run by a [`Driver`].
+pub trait Simulation {
+    type State: Clone;
+    /// What identifies a state for cycle detection, usually the parts of it
+    /// that change.
+    type Key: Hash + Eq;
+
+    fn initial_state(&self) -> Self::State;
+
+    fn step(&self, state: &mut Self::State);
+
+    /// Ends the run early, e.g. once the guard has left the floor.
+    fn is_terminal(&self, _state: &Self::State) -> bool {
+        return false;
+    }
+
+    fn render(&self, state: &Self::State) -> String;
+
+    fn state_key(&self, state: &Self::State) -> Self::Key;
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Ending {
+    /// Ran the number of ticks it was asked for.
+    Ticks,
+    Terminal,
+    /// The state after `start + length` ticks was already seen after `start`.
+    Cycle {
+        start: usize,
+        length: usize,
+    },
+}
+
+pub struct Run<State> {
+    pub state: State,
+    pub ticks: usize,
+    pub ending: Ending,
+    /// Every state from the initial one on, only kept when asked for.
+    pub history: Vec<State>,
+}
+
+impl<State> Run<State> {
+    pub fn state_at(&self, tick: usize) -> Option<&State> {
+        return self.history.get(tick);
+    }
+}
+
+/// Gets every rendered picture with the tick it shows.
+type RenderHook<'a> = Box<dyn FnMut(usize, String) + 'a>;
+
+/// Runs a simulation, optionally stopping at the first repeated state, keeping
+/// the history for replay and rendering as it goes.
+pub struct Driver<'a, S: Simulation> {
+    simulation: &'a S,
+    detect_cycles: bool,
+    keep_history: bool,
+    render_hook: Option<(usize, RenderHook<'a>)>,
+}
+
+impl<'a, S: Simulation> Driver<'a, S> {
+    pub fn new(simulation: &'a S) -> Self {
+        return Driver {
+            simulation,
+            detect_cycles: false,
+            keep_history: false,
+            render_hook: None,
+        };
+    }
+
+    pub fn with_cycle_detection(mut self) -> Self {
+        self.detect_cycles = true;
+        return self;
+    }
+
+    pub fn with_history(mut self) -> Self {
+        self.keep_history = true;
+        return self;
+    }
+
+    /// Renders the initial state and every `every` ticks after it, and passes
+    /// the picture to `hook` with its tick.
+    pub fn render_every(mut self, every: usize, hook: impl FnMut(usize, String) + 'a) -> Self {
+        self.render_hook = Some((every.max(1), Box::new(hook)));
+        return self;
+    }
+
+    /// Steps until `max_ticks`, a terminal state or, with cycle detection, a
+    /// repeated state. The ticks taken are counted as simulation steps.
+    pub fn run(mut self, max_ticks: usize) -> Run<S::State> {
+        let simulation = self.simulation;
+        let mut state = simulation.initial_state();
+        let mut tick = 0;
+        let mut seen = HashMap::new();
+        let mut history = vec![];
+
+        let ending = loop {
+            if self.keep_history {
+                history.push(state.clone());
+            }
+            if let Some((every, hook)) = &mut self.render_hook {
+                if tick % *every == 0 {
+                    hook(tick, simulation.render(&state));
+                }
+            }
+            if self.detect_cycles {
+                if let Some(start) = seen.insert(simulation.state_key(&state), tick) {
+                    break Ending::Cycle {
+                        start,
+                        length: tick - start,
+                    };
+                }
+            }
+
+            if simulation.is_terminal(&state) {
+                break Ending::Terminal;
+            }
+            if tick == max_ticks {
+                break Ending::Ticks;
+            }
+
+            simulation.step(&mut state);
+            tick += 1;
+        };
+        metrics::add("simulation steps", tick);
+
+        return Run {
+            state,
+            ticks: tick,
+            ending,
+            history,
+        };
+    }
+}
+
+/// Renders every state a run kept, in order.
+pub fn replay<S: Simulation>(simulation: &S, run: &Run<S::State>) -> Vec<String> {
+    return run
+        .history
+        .iter()
+        .map(|state| simulation.render(state))
+        .collect();
+}
+
+#[cfg(test)]
+struct Counter {
+    modulus: usize,
+    stop_at: Option<usize>,
+}
+
+#[cfg(test)]
+impl Simulation for Counter {
+    type State = usize;
+    type Key = usize;
+
+    fn initial_state(&self) -> usize {
+        return 0;
+    }
+
+    fn step(&self, state: &mut usize) {
+        *state = (*state + 1) % self.modulus;
+    }
+
+    fn is_terminal(&self, state: &usize) -> bool {
+        return Some(*state) == self.stop_at;
+    }
+
+    fn render(&self, state: &usize) -> String {
+        return state.to_string();
+    }
+
+    fn state_key(&self, state: &usize) -> usize {
+        return *state;
+    }
+}
+
+#[test]
+fn driver_stops_at_ticks_terminal_states_and_cycles() {
+    let counter = Counter {
+        modulus: 5,
+        stop_at: None,
+    };
+
+    let run = Driver::new(&counter).run(7);
+    assert_eq!((run.state, run.ticks, run.ending), (2, 7, Ending::Ticks));
+
+    let run = Driver::new(&counter).with_cycle_detection().run(100);
+    assert_eq!(
+        run.ending,
+        Ending::Cycle {
+            start: 0,
+            length: 5
+        }
+    );
+
+    let stopping = Counter {
+        modulus: 5,
+        stop_at: Some(3),
+    };
+    let run = Driver::new(&stopping).run(100);
+    assert_eq!((run.state, run.ending), (3, Ending::Terminal));
+}
+
+#[test]
+fn history_replays_and_render_hook_sees_every_nth_tick() {
+    let counter = Counter {
+        modulus: 10,
+        stop_at: None,
+    };
+    let mut rendered = vec![];
+
+    let run = Driver::new(&counter)
+        .with_history()
+        .render_every(2, |tick, picture| rendered.push((tick, picture)))
+        .run(4);
+
+    assert_eq!(replay(&counter, &run), vec!["0", "1", "2", "3", "4"]);
+    assert_eq!(run.state_at(3), Some(&3));
+    assert_eq!(
+        rendered,
+        vec![
+            (0, "0".to_string()),
+            (2, "2".to_string()),
+            (4, "4".to_string())
+        ]
+    );
+}
@@ -0,0 +1,142 @@
+use std::{
+    fs, io,
+    path::{Path, PathBuf},
+};
+
+use crate::input::day_directory;
+
+/// Pending snapshots are written next to the golden file with this suffix added.
+const PENDING_SUFFIX: &str = ".new";
+
+/// Where the golden copy of a day's snapshot lives, e.g.
+/// `src/year2024/day8/snapshots/example_antinodes.txt`.
+pub fn snapshot_path(year: usize, day: usize, name: &str) -> PathBuf {
+    return day_directory(year, day)
+        .join("snapshots")
+        .join(format!("{name}.txt"));
+}
+
+pub fn pending_path(golden: &Path) -> PathBuf {
+    let mut pending = golden.as_os_str().to_owned();
+    pending.push(PENDING_SUFFIX);
+    return PathBuf::from(pending);
+}
+
+/// Compares `actual` with the golden snapshot. When it differs or there is no
+/// golden file yet, `actual` is written as a pending snapshot to review with
+/// `snapshots review` and the test fails.
+pub fn assert_snapshot(year: usize, day: usize, name: &str, actual: &str) {
+    let golden = snapshot_path(year, day, name);
+    let pending = pending_path(&golden);
+    let expected = fs::read_to_string(&golden).ok();
+
+    if expected.as_deref() == Some(actual) {
+        fs::remove_file(&pending).ok();
+        return;
+    }
+
+    if let Some(directory) = pending.parent() {
+        fs::create_dir_all(directory).unwrap();
+    }
+    fs::write(&pending, actual).unwrap();
+
+    match expected {
+        Some(expected) => panic!(
+            "snapshot {} changed, wrote {}\n{}",
+            golden.display(),
+            pending.display(),
+            format_line_diff(&expected, actual)
+        ),
+        None => panic!(
+            "no snapshot {} yet, wrote {}",
+            golden.display(),
+            pending.display()
+        ),
+    }
+}
+
+/// Every pending snapshot below `root`, sorted by path.
+pub fn find_pending(root: &Path) -> io::Result<Vec<PathBuf>> {
+    let mut pending = vec![];
+    let mut directories = vec![root.to_path_buf()];
+
+    while let Some(directory) = directories.pop() {
+        for entry in fs::read_dir(&directory)? {
+            let path = entry?.path();
+            if path.is_dir() {
+                directories.push(path);
+            } else if path.to_string_lossy().ends_with(PENDING_SUFFIX) {
+                pending.push(path);
+            }
+        }
+    }
+
+    pending.sort();
+    return Ok(pending);
+}
+
+/// The golden file a pending snapshot would replace.
+pub fn golden_path(pending: &Path) -> PathBuf {
+    let pending = pending.to_string_lossy();
+    return PathBuf::from(pending.strip_suffix(PENDING_SUFFIX).unwrap_or(&pending));
+}
+
+/// Describes what accepting `pending` would change in its golden file.
+pub fn review_pending(pending: &Path) -> io::Result<String> {
+    let golden = golden_path(pending);
+    let actual = fs::read_to_string(pending)?;
+
+    return Ok(match fs::read_to_string(&golden) {
+        Ok(expected) => format!(
+            "{}\n{}",
+            golden.display(),
+            format_line_diff(&expected, &actual)
+        ),
+        Err(_) => format!(
+            "{} (new)\n{}",
+            golden.display(),
+            format_line_diff("", &actual)
+        ),
+    });
+}
+
+/// Moves the pending snapshot over its golden file.
+pub fn accept_pending(pending: &Path) -> io::Result<PathBuf> {
+    let golden = golden_path(pending);
+    fs::rename(pending, &golden)?;
+    return Ok(golden);
+}
+
+/// Boards are compared line by line, which is enough to spot the cells that
+/// moved without a full diff algorithm. Unchanged lines are kept for context.
+pub fn format_line_diff(expected: &str, actual: &str) -> String {
+    let expected_lines = expected.lines().collect::<Vec<&str>>();
+    let actual_lines = actual.lines().collect::<Vec<&str>>();
+    let mut output = String::new();
+
+    for index in 0..expected_lines.len().max(actual_lines.len()) {
+        match (expected_lines.get(index), actual_lines.get(index)) {
+            (Some(expected), Some(actual)) if expected == actual => {
+                output += &format!("  {expected}\n")
+            }
+            (expected, actual) => {
+                if let Some(expected) = expected {
+                    output += &format!("- {expected}\n");
+                }
+                if let Some(actual) = actual {
+                    output += &format!("+ {actual}\n");
+                }
+            }
+        }
+    }
+
+    return output;
+}
+
+#[test]
+fn line_diff_marks_changed_and_extra_lines() {
+    assert_eq!(
+        format_line_diff("#..\n.#.\n", "#..\n..#\n#..\n"),
+        "  #..\n- .#.\n+ ..#\n+ #..\n"
+    );
+}
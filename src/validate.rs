@@ -0,0 +1,478 @@
+use crate::parse::{self, Line, ParseError};
+
+/// Checks the shape of `day`'s input before a solver sees it. Every problem
+/// found is returned, in input order, rather than stopping at the first one.
+pub fn validate_input(year: usize, day: usize, text: &str) -> Vec<ParseError> {
+    let sections = parse::sections(text);
+    if sections.is_empty() {
+        return vec![ParseError::new(1, 1, "input is empty")];
+    }
+
+    return match year {
+        2024 => validate_2024(day, text, &sections),
+        _ => vec![],
+    };
+}
+
+fn validate_2024(day: usize, text: &str, sections: &[Vec<Line>]) -> Vec<ParseError> {
+    let mut problems = match day {
+        1 => check_lines(text, |line| check_integer_count(line, 2)),
+        // How short a report may be is up to the safety policy, not the input.
+        2 => check_lines(text, check_integers),
+        3 => vec![],
+        4 => check_grid(&sections[0], |tile| "XMAS".contains(tile), "X, M, A or S"),
+        5 => check_sections(sections, 2, |index, lines| match index {
+            0 => lines
+                .iter()
+                .flat_map(|line| check_separated_integers(line, '|', Some(2)))
+                .collect(),
+            _ => lines
+                .iter()
+                .flat_map(|line| {
+                    let mut problems = check_separated_integers(line, ',', None);
+                    if line.text.split(',').count() % 2 == 0 {
+                        problems.push(line.error(1, "an update needs a middle page"));
+                    }
+                    return problems;
+                })
+                .collect(),
+        }),
+        6 => {
+            let mut problems = check_grid(&sections[0], |tile| ".#^".contains(tile), ". # or ^");
+            problems.extend(check_exactly_one(&sections[0], '^', "guard"));
+            problems
+        }
+        7 => check_lines(text, check_calibration),
+        8 => check_grid(
+            &sections[0],
+            |tile| tile == '.' || tile.is_ascii_alphanumeric(),
+            ". or an antenna letter or digit",
+        ),
+        9 => check_single_line(sections, |line| {
+            check_characters(line, |character| character.is_ascii_digit(), "a digit")
+        }),
+        10 => check_grid(&sections[0], |tile| tile.is_ascii_digit(), "a digit"),
+        11 => check_single_line(sections, check_integers),
+        12 => check_grid(
+            &sections[0],
+            |tile| tile.is_ascii_uppercase(),
+            "an uppercase plant letter",
+        ),
+        13 => sections
+            .iter()
+            .flat_map(|machine| check_machine(machine))
+            .collect(),
+        14 => check_lines(text, |line| {
+            let mut problems = check_integer_count(line, 4);
+            if !line.text.starts_with("p=") || !line.text.contains(" v=") {
+                problems.push(line.error(1, "expected p=x,y v=dx,dy"));
+            }
+            return problems;
+        }),
+        15 => check_sections(sections, 2, |index, lines| match index {
+            0 => {
+                let mut problems = check_grid(lines, |tile| "#.O@".contains(tile), "# . O or @");
+                problems.extend(check_exactly_one(lines, '@', "robot"));
+                problems
+            }
+            _ => lines
+                .iter()
+                .flat_map(|line| check_characters(line, |tile| "<>^v".contains(tile), "a move"))
+                .collect(),
+        }),
+        16 | 20 => {
+            let mut problems = check_grid(&sections[0], |tile| "#.SE".contains(tile), "# . S or E");
+            problems.extend(check_exactly_one(&sections[0], 'S', "start"));
+            problems.extend(check_exactly_one(&sections[0], 'E', "end"));
+            problems
+        }
+        17 => check_sections(sections, 2, |index, lines| match index {
+            0 => check_registers(lines),
+            _ => check_program(lines),
+        }),
+        18 => check_lines(text, |line| check_separated_integers(line, ',', Some(2))),
+        19 => check_sections(sections, 2, |index, lines| {
+            lines
+                .iter()
+                .flat_map(|line| match index {
+                    0 => check_characters(line, |tile| "wubrg, ".contains(tile), "a towel colour"),
+                    _ => check_characters(line, |tile| "wubrg".contains(tile), "a stripe colour"),
+                })
+                .collect()
+        }),
+        21 => check_lines(text, |line| check_integer_count(line, 1)),
+        22 => check_sections(sections, 2, |index, lines| {
+            lines
+                .iter()
+                .flat_map(|line| match index {
+                    0 => check_wire(line),
+                    _ => check_gate(line),
+                })
+                .collect()
+        }),
+        _ => vec![],
+    };
+
+    if sections.len() > 1 && !matches!(day, 3 | 5 | 13 | 15 | 17 | 19 | 22) {
+        problems.extend(
+            sections[1..]
+                .iter()
+                .map(|section| ParseError::new(section[0].number - 1, 1, "unexpected blank line")),
+        );
+    }
+
+    problems.sort_by_key(|problem| (problem.line, problem.column));
+    return problems;
+}
+
+fn check_lines(text: &str, check: impl Fn(&Line) -> Vec<ParseError>) -> Vec<ParseError> {
+    return parse::lines(text).iter().flat_map(check).collect();
+}
+
+fn check_sections(
+    sections: &[Vec<Line>],
+    expected: usize,
+    check: impl Fn(usize, &[Line]) -> Vec<ParseError>,
+) -> Vec<ParseError> {
+    let mut problems = vec![];
+    if sections.len() != expected {
+        let last_line = sections.last().and_then(|section| section.last());
+        problems.push(ParseError::new(
+            last_line.map(|line| line.number).unwrap_or(1),
+            1,
+            format!(
+                "expected {expected} sections separated by a blank line, found {}",
+                sections.len()
+            ),
+        ));
+    }
+
+    for (index, section) in sections.iter().enumerate().take(expected) {
+        problems.extend(check(index, section));
+    }
+    return problems;
+}
+
+fn check_single_line(
+    sections: &[Vec<Line>],
+    check: impl Fn(&Line) -> Vec<ParseError>,
+) -> Vec<ParseError> {
+    let lines = sections.concat();
+    let mut problems = check(&lines[0]);
+    problems.extend(
+        lines[1..]
+            .iter()
+            .map(|line| line.error(1, "expected everything on a single line")),
+    );
+    return problems;
+}
+
+fn check_characters(
+    line: &Line,
+    is_allowed: impl Fn(char) -> bool,
+    description: &str,
+) -> Vec<ParseError> {
+    return line
+        .text
+        .chars()
+        .enumerate()
+        .filter(|(_, character)| !is_allowed(*character))
+        .map(|(index, character)| {
+            line.error(index + 1, format!("{:?} is not {description}", character))
+        })
+        .collect();
+}
+
+/// Rectangular, and every tile is allowed. Each bad tile is its own problem.
+fn check_grid(
+    lines: &[Line],
+    is_allowed: impl Fn(char) -> bool,
+    description: &str,
+) -> Vec<ParseError> {
+    let mut problems = vec![];
+    for line in lines {
+        // Reports every ragged row, not just the first one like `grid_of_lines`.
+        if let Err(error) = parse::grid_of_lines(&[lines[0], *line]) {
+            problems.push(error);
+        }
+    }
+
+    for line in lines {
+        problems.extend(check_characters(line, &is_allowed, description));
+    }
+    return problems;
+}
+
+fn check_exactly_one(lines: &[Line], tile: char, name: &str) -> Vec<ParseError> {
+    let found = lines
+        .iter()
+        .flat_map(|line| {
+            line.text
+                .chars()
+                .enumerate()
+                .filter(|(_, character)| *character == tile)
+                .map(|(index, _)| line.error(index + 1, format!("another {name} {:?}", tile)))
+        })
+        .collect::<Vec<ParseError>>();
+
+    if found.is_empty() {
+        return vec![lines[0].error(1, format!("no {name} {:?} in the grid", tile))];
+    }
+    return found.into_iter().skip(1).collect();
+}
+
+fn check_integers(line: &Line) -> Vec<ParseError> {
+    return match line.whitespace_separated::<i64>() {
+        Ok(_) => vec![],
+        Err(error) => vec![error],
+    };
+}
+
+fn check_integer_count(line: &Line, expected: usize) -> Vec<ParseError> {
+    let integers = match line.integers::<i64>() {
+        Ok(integers) => integers,
+        Err(error) => return vec![error],
+    };
+
+    if integers.len() != expected {
+        return vec![line.error(
+            1,
+            format!("expected {expected} numbers, found {}", integers.len()),
+        )];
+    }
+    return vec![];
+}
+
+fn check_separated_integers(
+    line: &Line,
+    separator: char,
+    expected: Option<usize>,
+) -> Vec<ParseError> {
+    let integers = match line.separated::<i64>(separator) {
+        Ok(integers) => integers,
+        Err(error) => return vec![error],
+    };
+
+    match expected {
+        Some(expected) if integers.len() != expected => vec![line.error(
+            1,
+            format!("expected {expected} numbers separated by {:?}", separator),
+        )],
+        _ => vec![],
+    }
+}
+
+fn check_calibration(line: &Line) -> Vec<ParseError> {
+    let (answer, values) = match line.key_value(":") {
+        Ok(key_value) => key_value,
+        Err(error) => return vec![error],
+    };
+
+    let mut problems = check_integer_count(&answer, 1);
+    problems.extend(check_integers(&values));
+    if values.text.is_empty() {
+        problems.push(values.error(1, "expected at least one number after the colon"));
+    }
+    return problems;
+}
+
+/// Two buttons and a prize, in that order.
+fn check_machine(machine: &[Line]) -> Vec<ParseError> {
+    let prefixes = ["Button A:", "Button B:", "Prize:"];
+    let mut problems = vec![];
+
+    for (index, line) in machine.iter().enumerate() {
+        match prefixes.get(index) {
+            Some(prefix) if line.text.starts_with(prefix) => {
+                problems.extend(check_integer_count(line, 2));
+            }
+            Some(prefix) => problems.push(line.error(1, format!("expected {:?}", prefix))),
+            None => problems.push(line.error(
+                1,
+                "a machine has only three lines, is a blank line missing?",
+            )),
+        }
+    }
+
+    if machine.len() < prefixes.len() {
+        let last_line = machine.last().unwrap();
+        problems.push(last_line.error(
+            1,
+            format!(
+                "machine ends early, expected {:?} next",
+                prefixes[machine.len()]
+            ),
+        ));
+    }
+    return problems;
+}
+
+fn check_registers(lines: &[Line]) -> Vec<ParseError> {
+    let mut problems = vec![];
+    for register in ["A", "B", "C"] {
+        let prefix = format!("Register {register}:");
+        match lines.iter().find(|line| line.text.starts_with(&prefix)) {
+            Some(line) => problems.extend(check_integer_count(line, 1)),
+            None => problems.push(lines[0].error(1, format!("missing {:?}", prefix))),
+        }
+    }
+    if lines.len() > 3 {
+        problems.extend(
+            lines[3..]
+                .iter()
+                .map(|line| line.error(1, "expected only three registers")),
+        );
+    }
+    return problems;
+}
+
+fn check_program(lines: &[Line]) -> Vec<ParseError> {
+    let line = &lines[0];
+    let mut problems = lines[1..]
+        .iter()
+        .map(|line| line.error(1, "expected the program on a single line"))
+        .collect::<Vec<ParseError>>();
+
+    let program = match line.key_value(":") {
+        Ok((key, program)) if key.text == "Program" => program,
+        _ => {
+            problems.push(line.error(1, "expected \"Program:\""));
+            return problems;
+        }
+    };
+
+    match program.separated::<u8>(',') {
+        Ok(instructions) => {
+            if instructions.iter().any(|instruction| *instruction > 7) {
+                problems.push(program.error(1, "instructions and operands are 3 bit numbers"));
+            }
+            if instructions.len() % 2 != 0 {
+                problems.push(program.error(1, "every instruction needs an operand"));
+            }
+        }
+        Err(error) => problems.push(error),
+    }
+    return problems;
+}
+
+fn check_wire(line: &Line) -> Vec<ParseError> {
+    return match line.key_value(":") {
+        Ok((_, value)) if value.text == "0" || value.text == "1" => vec![],
+        Ok((_, value)) => vec![value.error(1, "expected 0 or 1")],
+        Err(error) => vec![error],
+    };
+}
+
+fn check_gate(line: &Line) -> Vec<ParseError> {
+    let (gate, _) = match line.key_value("->") {
+        Ok(key_value) => key_value,
+        Err(error) => return vec![error],
+    };
+
+    match gate.text.split_whitespace().collect::<Vec<&str>>()[..] {
+        [_, "AND" | "OR" | "XOR", _] => vec![],
+        [_, operation, _] => vec![gate.sub(operation).error(1, "expected AND, OR or XOR")],
+        _ => vec![gate.error(1, "expected <wire> <gate> <wire>")],
+    }
+}
+
+#[test]
+fn committed_inputs_are_valid() {
+    use crate::{
+        input::{input_path, try_read_input},
+        input_store::{encrypted_path, StoreError},
+    };
+
+    let mut committed = 0;
+    for day in 1..=25 {
+        let path = input_path(2024, day);
+        if !path.exists() && !encrypted_path(&path).exists() {
+            continue;
+        }
+        committed += 1;
+
+        match try_read_input(2024, day, None) {
+            Ok(text) => assert_eq!(validate_input(2024, day, &text), vec![], "day {day}"),
+            Err(StoreError::MissingKey) => {
+                eprintln!("skipping 2024 day {day}: {}", StoreError::MissingKey);
+            }
+            Err(error) => panic!("2024 day {day}: {error}"),
+        }
+    }
+
+    assert!(committed > 0, "no committed inputs found");
+}
+
+#[test]
+fn committed_examples_are_valid() {
+    use crate::examples::{examples_directory, read_examples};
+
+    let mut checked = 0;
+    for day in 1..=25 {
+        let Ok(examples) = read_examples(&examples_directory(2024, day)) else {
+            continue;
+        };
+        for (index, text) in examples.inputs.iter().enumerate() {
+            assert_eq!(
+                validate_input(2024, day, text),
+                vec![],
+                "day {day} example {}",
+                index + 1
+            );
+            checked += 1;
+        }
+    }
+
+    assert!(checked > 0, "no committed examples found");
+}
+
+#[test]
+fn single_level_reports_are_left_to_the_policy() {
+    assert_eq!(validate_input(2024, 2, "7 6 4 2 1\n9\n"), vec![]);
+}
+
+#[test]
+fn every_bad_tile_is_reported() {
+    let problems = validate_input(2024, 10, "0123\n1.34\n87.5\n");
+
+    assert_eq!(
+        problems,
+        vec![
+            ParseError::new(2, 2, "'.' is not a digit"),
+            ParseError::new(3, 3, "'.' is not a digit"),
+        ]
+    );
+}
+
+#[test]
+fn grids_need_exactly_one_start() {
+    let problems = validate_input(2024, 6, "..^.\n.#..\n^...\n");
+
+    assert_eq!(problems, vec![ParseError::new(3, 1, "another guard '^'")]);
+}
+
+#[test]
+fn machines_missing_a_blank_line_are_reported() {
+    let text = "Button A: X+94, Y+34
+Button B: X+22, Y+67
+Prize: X=8400, Y=5400
+Button A: X+26, Y+66
+Button B: X+67, Y+21
+Prize: X=12748, Y=12176
+";
+
+    let problems = validate_input(2024, 13, text);
+
+    assert_eq!(problems.len(), 3);
+    assert_eq!(problems[0].line, 4);
+}
+
+#[test]
+fn blank_lines_inside_line_based_inputs_are_reported() {
+    let problems = validate_input(2024, 7, "190: 10 19\n\n3267: 81 40 27\n\n\n");
+
+    assert_eq!(
+        problems,
+        vec![ParseError::new(2, 1, "unexpected blank line")]
+    );
+}
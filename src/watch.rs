@@ -0,0 +1,138 @@
+use std::{ffi::OsStr, io, process::Command, thread, time::Duration};
+
+use inotify::{Inotify, WatchMask};
+
+use crate::{
+    examples::examples_directory,
+    input::day_directory,
+    registry::find_solutions,
+    runner::{run_solutions, RunOptions, RunResult},
+};
+
+/// Editors often write a file in several steps, so changes are collected for a
+/// moment before re-running.
+const SETTLE_TIME: Duration = Duration::from_millis(200);
+
+/// The answer of every part from one run, `None` when it didn't finish.
+pub type Answers = Vec<(usize, Option<String>)>;
+
+/// Re-runs every part of `day` and its example tests whenever a text file in
+/// the day's directory or its examples changes, printing how the answers moved.
+/// Only inputs and examples are watched: the solvers are compiled into this
+/// binary, so editing a `.rs` file needs the command restarted to take effect.
+/// Only returns on an error, the command is stopped with ctrl-c.
+pub fn watch_day(year: usize, day: usize, options: &RunOptions) -> io::Result<()> {
+    let solutions = find_solutions(year, day, None);
+    let directory = day_directory(year, day);
+    let examples = examples_directory(year, day);
+    let mask = WatchMask::CLOSE_WRITE | WatchMask::MOVED_TO | WatchMask::CREATE | WatchMask::DELETE;
+
+    let mut inotify = Inotify::init()?;
+    inotify.watches().add(&directory, mask)?;
+
+    let mut previous = None;
+    let mut buffer = [0; 4096];
+    loop {
+        let answers = answers_of(&run_solutions(&solutions, options));
+        run_example_tests(year, day);
+
+        if let Some(previous) = &previous {
+            println!();
+            print!("{}", format_answer_diff(previous, &answers));
+        }
+        previous = Some(answers);
+
+        // Watches aren't recursive. The examples may only have been extracted
+        // since the last run, adding the same directory again is a no-op.
+        if examples.is_dir() {
+            inotify.watches().add(&examples, mask)?;
+        }
+
+        println!();
+        println!(
+            "watching the inputs and examples in {} for changes, restart to pick up source edits...",
+            directory.display()
+        );
+        wait_for_text_file_change(&mut inotify, &mut buffer)?;
+        println!();
+    }
+}
+
+fn wait_for_text_file_change(inotify: &mut Inotify, buffer: &mut [u8]) -> io::Result<()> {
+    loop {
+        let events = inotify.read_events_blocking(buffer)?;
+        let is_text_file = |name: Option<&OsStr>| {
+            name.is_some_and(|name| {
+                let name = name.to_string_lossy();
+                name.ends_with(".txt") || name.ends_with(".tsv")
+            })
+        };
+
+        if events.into_iter().any(|event| is_text_file(event.name)) {
+            thread::sleep(SETTLE_TIME);
+            // Throw away whatever arrived while settling, one re-run covers it.
+            while inotify
+                .read_events(buffer)
+                .is_ok_and(|events| events.into_iter().next().is_some())
+            {}
+            return Ok(());
+        }
+    }
+}
+
+fn answers_of(results: &[RunResult]) -> Answers {
+    return results
+        .iter()
+        .map(|result| (result.solution.part, result.answer.clone()))
+        .collect();
+}
+
+/// The example tests are ordinary `#[test]`s next to the solution, so they're
+/// run through cargo, filtered down to the day's module.
+fn run_example_tests(year: usize, day: usize) {
+    let filter = format!("year{year}::day{day}::");
+    let status = Command::new("cargo")
+        .args(["test", "--quiet", "--lib", &filter])
+        .status();
+
+    match status {
+        Ok(status) if status.success() => println!("example tests passed"),
+        Ok(_) => println!("example tests FAILED"),
+        Err(error) => println!("could not run the example tests: {error}"),
+    }
+}
+
+pub fn format_answer_diff(previous: &Answers, current: &Answers) -> String {
+    let mut output = String::new();
+
+    for (part, answer) in current {
+        let before = previous
+            .iter()
+            .find(|(previous_part, _)| previous_part == part)
+            .and_then(|(_, answer)| answer.clone());
+        let describe = |answer: &Option<String>| answer.clone().unwrap_or("no answer".to_string());
+
+        if before == *answer {
+            output += &format!("part {part}: {} (unchanged)\n", describe(answer));
+        } else {
+            output += &format!(
+                "part {part}: {} -> {}\n",
+                describe(&before),
+                describe(answer)
+            );
+        }
+    }
+
+    return output;
+}
+
+#[test]
+fn answer_diff_marks_changed_parts() {
+    let previous = vec![(1, Some("143".to_string())), (2, Some("123".to_string()))];
+    let current = vec![(1, Some("143".to_string())), (2, None)];
+
+    assert_eq!(
+        format_answer_diff(&previous, &current),
+        "part 1: 143 (unchanged)\npart 2: 123 -> no answer\n"
+    );
+}
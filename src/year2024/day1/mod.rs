@@ -0,0 +1,580 @@
+use std::{
+    collections::HashMap,
+    io::{self, BufRead},
+};
+
+use crate::{
+    context::Context,
+    explain::markdown_table,
+    external_sort::{ExternalSorter, SortedValues},
+    input,
+    order_statistics::OrderStatisticTree,
+    parse::{self, Line, ParseError},
+};
+
+/// Rows kept in memory per sorted run when streaming.
+pub const DEFAULT_CHUNK_ROWS: usize = 1_000_000;
+const PERCENTILES: [usize; 6] = [10, 25, 50, 75, 90, 99];
+const HISTOGRAM_BUCKETS: u64 = 10;
+const HISTOGRAM_WIDTH: u64 = 40;
+
+fn read_lists() -> ListPair {
+    let file = input::read_input(2024, 1);
+    return ListPair::parse(&file).unwrap_or_else(|error| panic!("day 1 input: {error}"));
+}
+
+pub fn day_1_part_1(context: &Context) -> u128 {
+    let lists = read_lists();
+
+    let total = lists.distance(DistanceMetric::Absolute);
+    println!("difference score: {total}");
+
+    context.explain("Sorted lists", || {
+        let rows = lists
+            .sorted_pairs()
+            .map(|(element_1, element_2)| {
+                vec![
+                    element_1.to_string(),
+                    element_2.to_string(),
+                    DistanceMetric::Absolute
+                        .between(element_1, element_2)
+                        .to_string(),
+                ]
+            })
+            .collect::<Vec<Vec<String>>>();
+
+        return format!(
+            "Both lists sorted, paired up smallest to largest. The distances add up to {total}.\n\n{}",
+            markdown_table(&["left", "right", "distance"], &rows)
+        );
+    });
+
+    return total;
+}
+
+pub fn day_1_part_2() -> i64 {
+    let similarity_score = read_lists().similarity();
+    println!("similarity score: {similarity_score}");
+
+    return similarity_score;
+}
+
+/// How far apart two lists are once both are sorted and paired up smallest to
+/// largest.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DistanceMetric {
+    /// Sum of absolute differences, the puzzle's distance.
+    Absolute,
+    /// Sum of squared differences.
+    Squared,
+    /// Largest absolute difference of any pair.
+    Max,
+}
+
+impl DistanceMetric {
+    /// Wide enough for IDs at opposite ends of `i64`, even squared.
+    pub fn between(&self, left: i64, right: i64) -> u128 {
+        let difference = left.abs_diff(right) as u128;
+        match self {
+            DistanceMetric::Absolute | DistanceMetric::Max => return difference,
+            DistanceMetric::Squared => return difference * difference,
+        }
+    }
+
+    /// Adds `times` pairs that are each `distance` apart to `total`.
+    fn combine(&self, total: u128, distance: u128, times: usize) -> u128 {
+        match self {
+            DistanceMetric::Absolute | DistanceMetric::Squared => {
+                return total + distance * times as u128
+            }
+            DistanceMetric::Max => return total.max(distance),
+        }
+    }
+}
+
+/// The two location lists, side by side. Both always have the same length.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ListPair {
+    left: Vec<i64>,
+    right: Vec<i64>,
+}
+
+impl ListPair {
+    /// `None` when the lists have different lengths.
+    pub fn new(left: Vec<i64>, right: Vec<i64>) -> Option<Self> {
+        if left.len() != right.len() {
+            return None;
+        }
+
+        return Some(ListPair { left, right });
+    }
+
+    /// One pair of numbers per line, separated by any amount of whitespace.
+    pub fn parse(text: &str) -> Result<Self, ParseError> {
+        let mut left = vec![];
+        let mut right = vec![];
+
+        for line in parse::lines(text) {
+            let (element_1, element_2) = parse_row(&line)?;
+            left.push(element_1);
+            right.push(element_2);
+        }
+
+        return Ok(ListPair { left, right });
+    }
+
+    pub fn left(&self) -> &[i64] {
+        return &self.left;
+    }
+
+    pub fn right(&self) -> &[i64] {
+        return &self.right;
+    }
+
+    /// Both lists sorted, paired up smallest to largest.
+    pub fn sorted_pairs(&self) -> impl Iterator<Item = (i64, i64)> {
+        let mut left = self.left.clone();
+        left.sort();
+        let mut right = self.right.clone();
+        right.sort();
+
+        return left.into_iter().zip(right);
+    }
+
+    pub fn distance(&self, metric: DistanceMetric) -> u128 {
+        return self
+            .sorted_pairs()
+            .map(|(left, right)| metric.between(left, right))
+            .fold(0, |total, distance| metric.combine(total, distance, 1));
+    }
+
+    /// Every left number times how often it appears on the right.
+    pub fn similarity(&self) -> i64 {
+        let mut counts: HashMap<i64, i64> = HashMap::new();
+        for number in &self.right {
+            *counts.entry(*number).or_insert(0) += 1;
+        }
+
+        return self
+            .left
+            .iter()
+            .map(|number| number * counts.get(number).unwrap_or(&0))
+            .sum();
+    }
+}
+
+/// The lists built up one location ID at a time, e.g. while they are still
+/// being found. Both sides are kept as order-statistic trees, so the sorted
+/// pairing is there at any point without sorting again. The similarity is kept
+/// up to date on every push and removal, the distance isn't, see `distance`.
+#[derive(Debug, Clone, Default)]
+pub struct OnlineListPair {
+    left: OrderStatisticTree,
+    right: OrderStatisticTree,
+    similarity: i64,
+}
+
+impl OnlineListPair {
+    pub fn new() -> Self {
+        return OnlineListPair::default();
+    }
+
+    pub fn push_left(&mut self, number: i64) {
+        self.similarity += number * self.right.count(number) as i64;
+        self.left.insert(number);
+    }
+
+    pub fn push_right(&mut self, number: i64) {
+        self.similarity += number * self.left.count(number) as i64;
+        self.right.insert(number);
+    }
+
+    /// Takes one `number` back out of the left list, `false` when it isn't
+    /// there.
+    pub fn remove_left(&mut self, number: i64) -> bool {
+        if !self.left.remove(number) {
+            return false;
+        }
+
+        self.similarity -= number * self.right.count(number) as i64;
+        return true;
+    }
+
+    pub fn remove_right(&mut self, number: i64) -> bool {
+        if !self.right.remove(number) {
+            return false;
+        }
+
+        self.similarity -= number * self.left.count(number) as i64;
+        return true;
+    }
+
+    /// Adds one row of the input, numbered from 1 for errors.
+    pub fn push_row(&mut self, number: usize, text: &str) -> Result<(), ParseError> {
+        let (element_1, element_2) = parse_row(&Line::new(number, text))?;
+        self.push_left(element_1);
+        self.push_right(element_2);
+
+        return Ok(());
+    }
+
+    /// How many pairs there are, the shorter list's length.
+    pub fn pairs(&self) -> usize {
+        return self.left.len().min(self.right.len());
+    }
+
+    /// The `index`-th smallest number of each list.
+    pub fn pair(&self, index: usize) -> Option<(i64, i64)> {
+        if index >= self.pairs() {
+            return None;
+        }
+
+        return self.left.select(index).zip(self.right.select(index));
+    }
+
+    /// Kept up to date on every push and removal.
+    pub fn similarity(&self) -> i64 {
+        return self.similarity;
+    }
+
+    /// While one list is longer, its largest numbers have no partner yet and
+    /// are left out. Walks both trees' runs of repeated numbers, so each call
+    /// takes time in the number of distinct IDs. Ask between batches of rows
+    /// rather than after every push.
+    ///
+    /// Keeping the total up to date per insert or removal is out of scope: one
+    /// number moves every larger number onto a new partner, so the change
+    /// isn't a rank or prefix sum but the 1D earth mover's distance under
+    /// updates, which needs a kinetic segment tree over the IDs.
+    pub fn distance(&self, metric: DistanceMetric) -> u128 {
+        let mut left_runs = self.left.runs();
+        let mut right_runs = self.right.runs();
+        let mut left = left_runs.next();
+        let mut right = right_runs.next();
+        let mut total = 0;
+
+        while let (Some((element_1, count_1)), Some((element_2, count_2))) = (left, right) {
+            let times = count_1.min(count_2);
+            total = metric.combine(total, metric.between(element_1, element_2), times);
+
+            left = match count_1 - times {
+                0 => left_runs.next(),
+                remaining => Some((element_1, remaining)),
+            };
+            right = match count_2 - times {
+                0 => right_runs.next(),
+                remaining => Some((element_2, remaining)),
+            };
+        }
+
+        return total;
+    }
+}
+
+fn parse_row(line: &Line) -> Result<(i64, i64), ParseError> {
+    let elements = line.whitespace_separated::<i64>()?;
+    let [element_1, element_2] = elements[..] else {
+        return Err(line.error(1, "expected two numbers"));
+    };
+
+    return Ok((element_1, element_2));
+}
+
+/// Differences from `from` to `to`, both included.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct HistogramBucket {
+    pub from: u64,
+    pub to: u64,
+    pub count: u64,
+}
+
+/// Totals and the spread of the pair differences, for lists too large to
+/// hold in memory.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct StreamStatistics {
+    pub rows: u64,
+    pub total_distance: u128,
+    pub similarity: i128,
+    /// Nearest-rank percentiles of the sorted pairs' differences.
+    pub percentiles: Vec<(usize, u64)>,
+    pub histogram: Vec<HistogramBucket>,
+}
+
+impl StreamStatistics {
+    pub fn median_difference(&self) -> Option<u64> {
+        return self
+            .percentiles
+            .iter()
+            .find(|(percent, _)| *percent == 50)
+            .map(|(_, difference)| *difference);
+    }
+}
+
+/// Reads the lists a row at a time. Both columns are sorted externally twice,
+/// once to pair them up for the distance and once to count matching numbers
+/// for the similarity, and the differences go through another sort for the
+/// percentiles. Each sort holds at most `chunk_rows` numbers in memory, so
+/// memory doesn't grow with the lists or with how many distinct IDs they have.
+pub fn stream_statistics(reader: impl BufRead, chunk_rows: usize) -> io::Result<StreamStatistics> {
+    let mut left = ExternalSorter::new(chunk_rows);
+    let mut right = ExternalSorter::new(chunk_rows);
+    let mut left_for_similarity = ExternalSorter::new(chunk_rows);
+    let mut right_for_similarity = ExternalSorter::new(chunk_rows);
+    let mut rows = 0;
+
+    for (index, text) in reader.lines().enumerate() {
+        let text = text?;
+        if text.trim().is_empty() {
+            continue;
+        }
+        let (element_1, element_2) = parse_row(&Line::new(index + 1, &text))
+            .map_err(|error| io::Error::new(io::ErrorKind::InvalidData, error))?;
+
+        left_for_similarity.push(element_1)?;
+        right_for_similarity.push(element_2)?;
+        left.push(element_1)?;
+        right.push(element_2)?;
+        rows += 1;
+    }
+
+    let similarity = sorted_similarity(
+        left_for_similarity.finish()?,
+        right_for_similarity.finish()?,
+    )?;
+
+    let mut differences = ExternalSorter::new(chunk_rows);
+    let mut total_distance = 0;
+    let (mut lowest, mut highest) = (u64::MAX, u64::MIN);
+    for (element_1, element_2) in left.finish()?.zip(right.finish()?) {
+        let difference = element_1?.abs_diff(element_2?);
+        total_distance += difference as u128;
+        lowest = lowest.min(difference);
+        highest = highest.max(difference);
+        differences.push(difference)?;
+    }
+
+    let mut percentiles = vec![];
+    let mut histogram = vec![];
+    if rows > 0 {
+        let ranks = PERCENTILES.map(|percent| ((percent as u64 * rows).div_ceil(100)).max(1));
+        let width = (highest - lowest) / HISTOGRAM_BUCKETS + 1;
+        histogram = (0..=(highest - lowest) / width)
+            .map(|bucket| {
+                let from = lowest + bucket * width;
+                return HistogramBucket {
+                    from,
+                    to: from.saturating_add(width - 1),
+                    count: 0,
+                };
+            })
+            .collect::<Vec<HistogramBucket>>();
+
+        for (index, difference) in differences.finish()?.enumerate() {
+            let difference = difference?;
+            let rank = index as u64 + 1;
+            for (_, percent) in ranks
+                .iter()
+                .zip(PERCENTILES)
+                .filter(|(percent_rank, _)| **percent_rank == rank)
+            {
+                percentiles.push((percent, difference));
+            }
+            histogram[((difference - lowest) / width) as usize].count += 1;
+        }
+    }
+
+    return Ok(StreamStatistics {
+        rows,
+        total_distance,
+        similarity,
+        percentiles,
+        histogram,
+    });
+}
+
+/// Walks both sorted columns side by side, so each number's count on either
+/// side is a run and nothing has to be kept per ID.
+fn sorted_similarity(mut left: SortedValues, mut right: SortedValues) -> io::Result<i128> {
+    let mut similarity = 0;
+    let mut left_next = left.next().transpose()?;
+    let mut right_next = right.next().transpose()?;
+
+    while let (Some(element_1), Some(element_2)) = (left_next, right_next) {
+        if element_1 < element_2 {
+            left_next = left.next().transpose()?;
+        } else if element_2 < element_1 {
+            right_next = right.next().transpose()?;
+        } else {
+            let mut left_count = 0;
+            while left_next == Some(element_1) {
+                left_count += 1;
+                left_next = left.next().transpose()?;
+            }
+            let mut right_count = 0;
+            while right_next == Some(element_1) {
+                right_count += 1;
+                right_next = right.next().transpose()?;
+            }
+            similarity += element_1 as i128 * left_count * right_count;
+        }
+    }
+
+    return Ok(similarity);
+}
+
+pub fn format_stream_statistics(statistics: &StreamStatistics) -> String {
+    let mut report = format!(
+        "rows: {}\ntotal distance: {}\nsimilarity score: {}\n",
+        statistics.rows, statistics.total_distance, statistics.similarity
+    );
+    if let Some(median) = statistics.median_difference() {
+        report += &format!("median difference: {median}\n");
+    }
+    if !statistics.percentiles.is_empty() {
+        let percentiles = statistics
+            .percentiles
+            .iter()
+            .map(|(percent, difference)| format!("p{percent} {difference}"))
+            .collect::<Vec<String>>();
+        report += &format!("percentiles: {}\n", percentiles.join(", "));
+    }
+
+    let most = statistics
+        .histogram
+        .iter()
+        .map(|bucket| bucket.count)
+        .max()
+        .unwrap_or(0)
+        .max(1);
+    for bucket in &statistics.histogram {
+        let range = format!("{}..={}", bucket.from, bucket.to);
+        let bar = "#".repeat((bucket.count * HISTOGRAM_WIDTH).div_ceil(most) as usize);
+        report += &format!("{range:>15} {bar} {}\n", bucket.count);
+    }
+
+    return report;
+}
+
+#[test]
+fn example_lists() {
+    let lists = ListPair::parse("3   4\n4 3\n2\t5\n1   3\n3   9\n3   3\n").unwrap();
+
+    assert_eq!(lists.distance(DistanceMetric::Absolute), 11);
+    assert_eq!(
+        lists.distance(DistanceMetric::Squared),
+        4 + 1 + 0 + 1 + 4 + 25
+    );
+    assert_eq!(lists.distance(DistanceMetric::Max), 5);
+    assert_eq!(lists.similarity(), 31);
+}
+
+#[test]
+fn ids_at_the_ends_of_the_range_do_not_overflow() {
+    let lists = ListPair::new(vec![i64::MIN], vec![i64::MAX]).unwrap();
+    let widest = u64::MAX as u128;
+
+    assert_eq!(lists.distance(DistanceMetric::Absolute), widest);
+    assert_eq!(lists.distance(DistanceMetric::Squared), widest * widest);
+    assert_eq!(lists.distance(DistanceMetric::Max), widest);
+
+    let text = format!("{} {}\n", i64::MIN, i64::MAX);
+    let statistics = stream_statistics(text.as_bytes(), 1).unwrap();
+    assert_eq!(statistics.total_distance, widest);
+    assert_eq!(statistics.median_difference(), Some(u64::MAX));
+    assert_eq!(statistics.histogram[0].to, u64::MAX);
+}
+
+#[test]
+fn lines_without_two_numbers_are_errors() {
+    let error = ListPair::parse("3   4\n4   3   2\n").unwrap_err();
+
+    assert_eq!(
+        error.to_string(),
+        ParseError::new(2, 1, "expected two numbers").to_string()
+    );
+    assert!(ListPair::parse("3   x\n").is_err());
+    assert_eq!(ListPair::new(vec![1], vec![]), None);
+}
+
+#[test]
+fn streamed_example_matches_the_lists() {
+    let text = "3   4\n4 3\n2\t5\n\n1   3\n3   9\n3   3\n";
+    let lists = ListPair::parse(text).unwrap();
+
+    let statistics = stream_statistics(text.as_bytes(), 2).unwrap();
+
+    assert_eq!(statistics.rows, 6);
+    assert_eq!(
+        statistics.total_distance,
+        lists.distance(DistanceMetric::Absolute)
+    );
+    assert_eq!(statistics.similarity, lists.similarity() as i128);
+    // Differences sorted: 0 1 1 2 2 5
+    assert_eq!(statistics.median_difference(), Some(1));
+    assert_eq!(
+        statistics
+            .histogram
+            .iter()
+            .map(|bucket| bucket.count)
+            .collect::<Vec<u64>>(),
+        vec![1, 2, 2, 0, 0, 1]
+    );
+
+    let error = stream_statistics("1 2\n3\n".as_bytes(), 2).unwrap_err();
+    assert_eq!(error.kind(), io::ErrorKind::InvalidData);
+}
+
+#[test]
+fn online_lists_agree_with_the_whole_lists_after_every_row() {
+    let text = "3   4\n4   3\n2   5\n1   3\n3   9\n3   3\n";
+    let mut online = OnlineListPair::new();
+
+    for (index, row) in text.lines().enumerate() {
+        online.push_row(index + 1, row).unwrap();
+
+        let lists = ListPair::parse(
+            &text
+                .lines()
+                .take(index + 1)
+                .collect::<Vec<&str>>()
+                .join("\n"),
+        )
+        .unwrap();
+        for metric in [
+            DistanceMetric::Absolute,
+            DistanceMetric::Squared,
+            DistanceMetric::Max,
+        ] {
+            assert_eq!(online.distance(metric), lists.distance(metric));
+        }
+        assert_eq!(online.similarity(), lists.similarity());
+    }
+
+    assert_eq!(online.pair(0), Some((1, 3)));
+    assert_eq!(online.pair(5), Some((4, 9)));
+    assert_eq!(online.pair(6), None);
+
+    // The new 5 waits for a partner on the right, and matches the 5 already there
+    online.push_left(5);
+    assert_eq!(online.distance(DistanceMetric::Absolute), 11);
+    assert_eq!(online.similarity(), 31 + 5);
+    online.push_right(5);
+    assert_eq!(online.distance(DistanceMetric::Absolute), 11);
+    assert_eq!(online.similarity(), 31 + 5 + 5);
+
+    // Back to the example without its first row
+    assert!(online.remove_left(5) && online.remove_right(5));
+    assert!(online.remove_left(3) && online.remove_right(4));
+    assert!(!online.remove_left(7));
+    let lists = ListPair::parse("4   3\n2   5\n1   3\n3   9\n3   3\n").unwrap();
+    assert_eq!(
+        online.distance(DistanceMetric::Absolute),
+        lists.distance(DistanceMetric::Absolute)
+    );
+    assert_eq!(online.similarity(), lists.similarity());
+}
+
+#[test]
+fn examples_solve() {
+    crate::examples::assert_examples_solve(2024, 1);
+}
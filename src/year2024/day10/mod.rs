@@ -1,52 +1,48 @@
-use std::{
-    collections::HashMap,
-    fs,
-};
+use std::collections::HashMap;
 
 use strum::{EnumIter, IntoEnumIterator};
 
+use crate::{input, memo::Memo, parse};
+
 fn read_input() -> Vec<Vec<u32>> {
-    let text = fs::read_to_string("src/day10/input.txt").expect("input file not found");
-
-    let vector_2d: Vec<Vec<u32>> = text
-        .split("\n")
-        .map(|line| {
-            line.chars()
-                .map(|char| char.to_digit(10).unwrap())
-                .collect::<Vec<u32>>()
-        })
-        .collect();
-
-    return vector_2d;
+    let text = input::read_input(2024, 10);
+
+    return parse::digit_grid(&text).unwrap_or_else(|error| panic!("day 10 input: {error}"));
 }
 
-pub fn day_10_part_1() {
+pub fn day_10_part_1() -> usize {
     let input = read_input();
     let starting_points = find_all_start_locations(&input);
     print_board(&input);
 
+    let mut memo = Memo::new();
     let mut total = 0;
     for point in starting_points {
-        let paths_to_summit = find_next_viable_steps_with_directions(&input, point);
+        let paths_to_summit = find_next_viable_steps_with_directions(&mut memo, &input, point);
 
         total += paths_to_summit.len();
     }
-    println!("total: {total}")
+    println!("total: {total}");
+
+    return total;
 }
 
-pub fn day_10_part_2() {
+pub fn day_10_part_2() -> usize {
     let input = read_input();
     let starting_points = find_all_start_locations(&input);
 
+    let mut memo = Memo::new();
     let mut total = 0;
     for point in starting_points {
-        let paths_to_summit = find_next_viable_steps_with_directions(&input, point);
+        let paths_to_summit = find_next_viable_steps_with_directions(&mut memo, &input, point);
 
         for (_, path_count) in paths_to_summit {
             total += path_count
         }
     }
-    println!("total: {total}")
+    println!("total: {total}");
+
+    return total;
 }
 
 fn find_all_start_locations(input: &Vec<Vec<u32>>) -> Vec<Coordinate> {
@@ -63,31 +59,36 @@ fn find_all_start_locations(input: &Vec<Vec<u32>>) -> Vec<Coordinate> {
     return coordinates;
 }
 
+/// The summits reachable from `coordinate` and the number of trails to each.
+/// Only depends on the coordinate, so trails that meet share the work.
 fn find_next_viable_steps_with_directions(
+    memo: &mut Memo<Coordinate, HashMap<Coordinate, usize>>,
     input: &Vec<Vec<u32>>,
     coordinate: Coordinate,
 ) -> HashMap<Coordinate, usize> {
-    let value = input[coordinate.y][coordinate.x];
-
-    let mut summits_reachable = HashMap::new();
-
-    for direction in Direction::iter() {
-        if let Some((value_in_direction, coordinate)) =
-            value_in_direction(input, coordinate, direction.clone())
-        {
-            if (value + 1) == value_in_direction {
-                if value_in_direction == 9 {
-                    *summits_reachable.entry(coordinate).or_insert(0) += 1;
-                }
-                let summits = find_next_viable_steps_with_directions(input, coordinate);
-                for (coordinate, count) in summits {
-                    *summits_reachable.entry(coordinate).or_insert(0) += count;
+    return memo.get_or_compute(coordinate, |memo| {
+        let value = input[coordinate.y][coordinate.x];
+
+        let mut summits_reachable = HashMap::new();
+
+        for direction in Direction::iter() {
+            if let Some((value_in_direction, coordinate)) =
+                value_in_direction(input, coordinate, direction.clone())
+            {
+                if (value + 1) == value_in_direction {
+                    if value_in_direction == 9 {
+                        *summits_reachable.entry(coordinate).or_insert(0) += 1;
+                    }
+                    let summits = find_next_viable_steps_with_directions(memo, input, coordinate);
+                    for (coordinate, count) in summits {
+                        *summits_reachable.entry(coordinate).or_insert(0) += count;
+                    }
                 }
             }
         }
-    }
 
-    return summits_reachable;
+        return summits_reachable;
+    });
 }
 
 fn value_in_direction(
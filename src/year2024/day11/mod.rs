@@ -0,0 +1,159 @@
+use std::cell::RefCell;
+
+use crate::{
+    input,
+    memo::Memo,
+    parse,
+    simulation::{Driver, Simulation},
+};
+
+fn read_input() -> Vec<usize> {
+    let input = input::read_input(2024, 11);
+
+    return parse::integers(&input).unwrap_or_else(|error| panic!("day 11 input: {error}"));
+}
+
+pub fn day_11_part_1() -> usize {
+    let input = read_input();
+
+    let number_of_blinks = 25;
+
+    let number_of_stones = count_stones_after_blinks(&input, number_of_blinks);
+
+    println!(
+        "{} stones after {} blinks",
+        number_of_stones, number_of_blinks
+    );
+
+    return number_of_stones;
+}
+
+pub fn day_11_part_2() -> usize {
+    let input = read_input();
+
+    let number_of_blinks = 75;
+
+    let number_of_stones = count_stones_after_blinks(&input, number_of_blinks);
+
+    println!(
+        "{} stones after {} blinks",
+        number_of_stones, number_of_blinks
+    );
+
+    return number_of_stones;
+}
+
+/// Blinking at the stones, one blink per tick. The line grows exponentially,
+/// so the state is only how many blinks happened, the stones are counted
+/// through the memo whenever they're looked at.
+struct Stones {
+    numbers: Vec<usize>,
+    memo: RefCell<Memo<(usize, usize), usize>>,
+}
+
+impl Stones {
+    fn new(numbers: Vec<usize>) -> Self {
+        return Stones {
+            numbers,
+            memo: RefCell::new(Memo::new()),
+        };
+    }
+
+    /// Stones never affect their neighbours, so the count is the sum over each
+    /// stone.
+    fn count(&self, blinks: usize) -> usize {
+        let mut memo = self.memo.borrow_mut();
+
+        return self
+            .numbers
+            .iter()
+            .map(|number| count_stones(&mut memo, *number, blinks))
+            .sum();
+    }
+}
+
+impl Simulation for Stones {
+    type State = usize;
+    type Key = usize;
+
+    fn initial_state(&self) -> usize {
+        return 0;
+    }
+
+    fn step(&self, blinks: &mut usize) {
+        *blinks += 1;
+    }
+
+    fn render(&self, blinks: &usize) -> String {
+        return format!("{} stones after {blinks} blinks", self.count(*blinks));
+    }
+
+    fn state_key(&self, blinks: &usize) -> usize {
+        return *blinks;
+    }
+}
+
+fn count_stones_after_blinks(input: &Vec<usize>, number_of_blinks: usize) -> usize {
+    let stones = Stones::new(input.clone());
+    let run = Driver::new(&stones).run(number_of_blinks);
+
+    return stones.count(run.state);
+}
+
+/// A stone's count only depends on its number and the blinks left.
+fn count_stones(memo: &mut Memo<(usize, usize), usize>, number: usize, blinks: usize) -> usize {
+    if blinks == 0 {
+        return 1;
+    }
+
+    return memo.get_or_compute((number, blinks), |memo| {
+        return blink(number)
+            .into_iter()
+            .map(|stone| count_stones(memo, stone, blinks - 1))
+            .sum();
+    });
+}
+
+fn blink(number: usize) -> Vec<usize> {
+    if number == 0 {
+        return vec![1];
+    }
+
+    if number.to_string().len() % 2 == 0 {
+        let number = number.to_string();
+
+        let (number_1, number_2) = number.split_at(number.len() / 2);
+
+        return vec![number_1.parse().unwrap(), number_2.parse().unwrap()];
+    }
+
+    return vec![number * 2024];
+}
+
+#[test]
+fn example_stones() {
+    let input = vec![125, 17];
+
+    assert_eq!(count_stones_after_blinks(&input, 6), 22);
+    assert_eq!(count_stones_after_blinks(&input, 25), 55312);
+}
+
+#[test]
+fn rendered_blinks_count_through_the_memo() {
+    let stones = Stones::new(vec![125, 17]);
+    let mut rendered = vec![];
+
+    Driver::new(&stones)
+        .render_every(2, |_, picture| rendered.push(picture))
+        .run(6);
+
+    assert_eq!(
+        rendered,
+        vec![
+            "2 stones after 0 blinks",
+            "4 stones after 2 blinks",
+            "9 stones after 4 blinks",
+            "22 stones after 6 blinks"
+        ]
+    );
+}
@@ -1,26 +1,21 @@
-use std::{
-    collections::{HashMap, HashSet},
-    fs,
-};
+use std::collections::{HashMap, HashSet};
 
 use strum::{EnumIter, IntoEnumIterator};
 
-fn read_input() -> Vec<Vec<char>> {
-    let input = fs::read_to_string("src/day12/input.txt").expect("Day 12 input not present");
+use crate::{context::Context, explain::markdown_table, input, parse};
 
-    let vector_2d: Vec<Vec<char>> = input
-        .split("\n")
-        .map(|line| line.chars().collect::<Vec<char>>())
-        .collect();
+fn read_input() -> Vec<Vec<char>> {
+    let input = input::read_input(2024, 12);
 
-    return vector_2d;
+    return parse::char_grid(&input).unwrap_or_else(|error| panic!("day 12 input: {error}"));
 }
 
-pub fn day_12_part_1() {
+pub fn day_12_part_1(context: &Context) -> usize {
     let input = read_input();
     let mut unexplored_coordinates = get_coordinates(&input);
 
     let mut total_price = 0;
+    let mut regions = vec![];
 
     loop {
         if unexplored_coordinates.is_empty() {
@@ -30,6 +25,7 @@ pub fn day_12_part_1() {
         if let Some(coordinate) = unexplored_coordinates.iter().next() {
             let (coordinates, circumference) = get_block_at_coordinate(&input, coordinate);
             let area_of_block = coordinates.len();
+            regions.push(Region::new(&input, &coordinates, circumference));
 
             for coordinate in coordinates {
                 unexplored_coordinates.remove(&coordinate);
@@ -41,13 +37,23 @@ pub fn day_12_part_1() {
     }
 
     println!("Total Price of Fences with circumference: {total_price}");
+
+    context.explain("Regions and their fences", || {
+        return format!(
+            "Each region is priced at its area times its perimeter.\n\n{}",
+            format_regions(regions, "perimeter")
+        );
+    });
+
+    return total_price;
 }
 
-pub fn day_12_part_2() {
+pub fn day_12_part_2(context: &Context) -> usize {
     let input = read_input();
     let mut unexplored_coordinates = get_coordinates(&input);
 
     let mut total_price = 0;
+    let mut regions = vec![];
 
     loop {
         if unexplored_coordinates.is_empty() {
@@ -58,6 +64,7 @@ pub fn day_12_part_2() {
             let (coordinates, _) = get_block_at_coordinate(&input, coordinate);
             let area_of_block = coordinates.len();
             let edges = count_sides(&coordinates);
+            regions.push(Region::new(&input, &coordinates, edges));
 
             for coordinate in coordinates {
                 unexplored_coordinates.remove(&coordinate);
@@ -70,6 +77,60 @@ pub fn day_12_part_2() {
     }
 
     println!("Total Price of Fences with Sides: {total_price}");
+
+    context.explain("Regions and their fences", || {
+        return format!(
+            "Each region is priced at its area times its number of sides, a straight run of fence counts once.\n\n{}",
+            format_regions(regions, "sides")
+        );
+    });
+
+    return total_price;
+}
+
+/// A region as the walkthrough shows it, `fences` is the perimeter or the
+/// number of sides depending on the part.
+struct Region {
+    plant: char,
+    top_left: Coordinate,
+    area: usize,
+    fences: usize,
+}
+
+impl Region {
+    fn new(input: &Vec<Vec<char>>, coordinates: &HashSet<Coordinate>, fences: usize) -> Self {
+        let top_left = *coordinates
+            .iter()
+            .min_by_key(|coordinate| (coordinate.y, coordinate.x))
+            .unwrap();
+
+        return Region {
+            plant: input[top_left.y][top_left.x],
+            top_left,
+            area: coordinates.len(),
+            fences,
+        };
+    }
+}
+
+/// Regions are found in hash order, so they're listed top to bottom instead.
+fn format_regions(mut regions: Vec<Region>, fence_name: &str) -> String {
+    regions.sort_by_key(|region| (region.top_left.y, region.top_left.x));
+
+    let rows = regions
+        .iter()
+        .map(|region| {
+            vec![
+                region.plant.to_string(),
+                format!("{},{}", region.top_left.x, region.top_left.y),
+                region.area.to_string(),
+                region.fences.to_string(),
+                (region.area * region.fences).to_string(),
+            ]
+        })
+        .collect::<Vec<Vec<String>>>();
+
+    return markdown_table(&["plant", "top left", "area", fence_name, "price"], &rows);
 }
 
 #[derive(PartialEq, EnumIter, Hash, Clone, Eq, Debug)]
@@ -0,0 +1,240 @@
+use crate::{
+    input,
+    number_theory::{solve_2x2, solve_linear_diophantine},
+    parse,
+};
+
+const PRIZE_OFFSET: i128 = 10000000000000;
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+struct ClawMachine {
+    button_a: (i128, i128),
+    button_b: (i128, i128),
+    prize: (i128, i128),
+}
+
+fn read_input() -> Vec<ClawMachine> {
+    let input = input::read_input(2024, 13);
+
+    return parse_machines(&input);
+}
+
+fn parse_machines(input: &str) -> Vec<ClawMachine> {
+    return parse::sections(input)
+        .iter()
+        .map(|machine| {
+            let mut numbers = vec![];
+            for line in machine {
+                numbers.extend(
+                    line.integers::<i128>()
+                        .unwrap_or_else(|error| panic!("day 13 input: {error}")),
+                );
+            }
+            let [a_x, a_y, b_x, b_y, prize_x, prize_y] = numbers[..] else {
+                panic!(
+                    "day 13 input: {}",
+                    machine[0].error(1, "expected two buttons and a prize")
+                );
+            };
+            return ClawMachine {
+                button_a: (a_x, a_y),
+                button_b: (b_x, b_y),
+                prize: (prize_x, prize_y),
+            };
+        })
+        .collect();
+}
+
+pub fn day_13_part_1() -> i128 {
+    let machines = read_input();
+
+    let total_cost = machines.iter().filter_map(cheapest_win).sum::<i128>();
+
+    println!("total cost to win all prizes: {total_cost}");
+
+    return total_cost;
+}
+
+pub fn day_13_part_2() -> i128 {
+    let machines = read_input();
+
+    let total_cost = machines
+        .iter()
+        .map(|machine| ClawMachine {
+            prize: (
+                machine.prize.0 + PRIZE_OFFSET,
+                machine.prize.1 + PRIZE_OFFSET,
+            ),
+            ..*machine
+        })
+        .filter_map(|machine| cheapest_win(&machine))
+        .sum::<i128>();
+
+    println!("total cost to win all prizes: {total_cost}");
+
+    return total_cost;
+}
+
+/// The fewest tokens that win the prize, A presses cost 3 and B presses 1.
+/// `None` when no whole, non-negative number of presses reaches it.
+fn cheapest_win(machine: &ClawMachine) -> Option<i128> {
+    let x_equation = [machine.button_a.0, machine.button_b.0, machine.prize.0];
+    let y_equation = [machine.button_a.1, machine.button_b.1, machine.prize.1];
+
+    if let Some((a_presses, b_presses)) = solve_2x2(x_equation, y_equation) {
+        if a_presses < 0 || b_presses < 0 {
+            return None;
+        }
+        return Some(a_presses * 3 + b_presses);
+    }
+
+    // Both buttons move along the same line, the prize has to be on it too.
+    let [a_x, b_x, prize_x] = x_equation;
+    let [a_y, b_y, prize_y] = y_equation;
+    if a_x * b_y != b_x * a_y || a_x * prize_y != prize_x * a_y || b_x * prize_y != prize_x * b_y {
+        return None;
+    }
+    // Without any x movement the x equation only says the prize is at x = 0.
+    if a_x == 0 && b_x == 0 {
+        return cheapest_along_line(a_y, b_y, prize_y);
+    }
+    return cheapest_along_line(a_x, b_x, prize_x);
+}
+
+/// Minimises `3 * a + b` over the non-negative solutions of
+/// `a_step * a + b_step * b = target`, whatever the signs of the steps. The
+/// cost changes linearly along the solutions and can't drop below 0, so the
+/// cheapest is at the end of the valid range the cost falls towards.
+fn cheapest_along_line(a_step: i128, b_step: i128, target: i128) -> Option<i128> {
+    let solutions = solve_linear_diophantine(a_step, b_step, target)?;
+
+    // a = x + k * x_step >= 0 and b = y - k * y_step >= 0
+    let mut lowest_k = None;
+    let mut highest_k = None;
+    for (start, slope) in [
+        (solutions.x, solutions.x_step),
+        (solutions.y, -solutions.y_step),
+    ] {
+        if slope > 0 {
+            let bound = (-start).div_euclid(slope) + ((-start).rem_euclid(slope) != 0) as i128;
+            lowest_k = Some(lowest_k.map_or(bound, |lowest: i128| lowest.max(bound)));
+        } else if slope < 0 {
+            let bound = start.div_euclid(-slope);
+            highest_k = Some(highest_k.map_or(bound, |highest: i128| highest.min(bound)));
+        } else if start < 0 {
+            return None;
+        }
+    }
+    if let (Some(lowest), Some(highest)) = (lowest_k, highest_k) {
+        if lowest > highest {
+            return None;
+        }
+    }
+
+    let cost_slope = solutions.x_step * 3 - solutions.y_step;
+    let k = if cost_slope > 0 {
+        lowest_k?
+    } else if cost_slope < 0 {
+        highest_k?
+    } else {
+        lowest_k.or(highest_k)?
+    };
+
+    return Some((solutions.x + k * solutions.x_step) * 3 + solutions.y - k * solutions.y_step);
+}
+
+#[test]
+fn example_machines() {
+    let machines = parse_machines(
+        "Button A: X+94, Y+34
+Button B: X+22, Y+67
+Prize: X=8400, Y=5400
+
+Button A: X+26, Y+66
+Button B: X+67, Y+21
+Prize: X=12748, Y=12176
+
+Button A: X+17, Y+86
+Button B: X+84, Y+37
+Prize: X=7870, Y=6450
+
+Button A: X+69, Y+23
+Button B: X+27, Y+71
+Prize: X=18641, Y=10279
+",
+    );
+
+    let costs = machines.iter().map(cheapest_win).collect::<Vec<_>>();
+
+    assert_eq!(costs, vec![Some(280), None, Some(200), None]);
+}
+
+#[test]
+fn buttons_on_the_same_line_pick_the_cheapest_presses() {
+    let machine = ClawMachine {
+        button_a: (3, 3),
+        button_b: (1, 1),
+        prize: (10, 10),
+    };
+
+    // Ten B presses cost 10, every A press swaps three of them for 3 tokens.
+    assert_eq!(cheapest_win(&machine), Some(10));
+
+    let machine = ClawMachine {
+        button_a: (4, 4),
+        button_b: (2, 2),
+        prize: (7, 7),
+    };
+    assert_eq!(cheapest_win(&machine), None);
+}
+
+#[test]
+fn buttons_without_x_movement_use_the_y_equation() {
+    let machine = ClawMachine {
+        button_a: (0, 3),
+        button_b: (0, 1),
+        prize: (0, 10),
+    };
+    assert_eq!(cheapest_win(&machine), Some(10));
+
+    let machine = ClawMachine {
+        button_a: (0, 3),
+        button_b: (0, 1),
+        prize: (5, 10),
+    };
+    assert_eq!(cheapest_win(&machine), None);
+}
+
+#[test]
+fn buttons_on_the_same_line_work_with_any_signs() {
+    let machine = ClawMachine {
+        button_a: (-3, -3),
+        button_b: (-1, -1),
+        prize: (-10, -10),
+    };
+    assert_eq!(cheapest_win(&machine), Some(10));
+
+    // A moves forward and B back, so 3 * a - b = 10 has presses without end,
+    // the fewest A presses are the cheapest.
+    let machine = ClawMachine {
+        button_a: (3, 3),
+        button_b: (-1, -1),
+        prize: (10, 10),
+    };
+    assert_eq!(cheapest_win(&machine), Some(14));
+
+    let machine = ClawMachine {
+        button_a: (-3, -3),
+        button_b: (1, 1),
+        prize: (10, 10),
+    };
+    assert_eq!(cheapest_win(&machine), Some(10));
+
+    // Both buttons move back, a prize ahead can't be reached.
+    let machine = ClawMachine {
+        button_a: (-3, -3),
+        button_b: (-1, -1),
+        prize: (10, 10),
+    };
+    assert_eq!(cheapest_win(&machine), None);
+}
@@ -0,0 +1,296 @@
+use std::isize;
+
+use crate::{
+    context::{Cancelled, Context},
+    input,
+    number_theory::chinese_remainder,
+    parse,
+    simulation::{Driver, Simulation},
+};
+
+fn read_input() -> FloorDescription {
+    let input = input::read_input(2024, 14);
+    let floor = input::read_day_file(2024, 14, "size.txt");
+
+    return parse_floor_description(&input, &floor);
+}
+
+fn parse_floor_description(input: &str, floor: &str) -> FloorDescription {
+    let numbers_in_floor_description =
+        parse::integers::<isize>(floor).unwrap_or_else(|error| panic!("day 14 size: {error}"));
+
+    let result = parse::lines(input)
+        .iter()
+        .map(|line| {
+            let numbers = line
+                .integers::<isize>()
+                .unwrap_or_else(|error| panic!("day 14 input: {error}"));
+            let [x, y, dx, dy] = numbers[..] else {
+                panic!("day 14 input: {}", line.error(1, "expected p=x,y v=dx,dy"));
+            };
+
+            return Robot {
+                position: Coordinate { x, y },
+                velocity: Velocity { dx, dy },
+            };
+        })
+        .collect::<Vec<Robot>>();
+
+    return FloorDescription {
+        robots: result,
+        floor: FloorDimensions {
+            width: numbers_in_floor_description[0],
+            height: numbers_in_floor_description[1],
+        },
+    };
+}
+
+#[derive(Debug, Clone)]
+struct FloorDescription {
+    robots: Vec<Robot>,
+    floor: FloorDimensions,
+}
+
+#[derive(Debug, Copy, Clone)]
+struct FloorDimensions {
+    width: isize,
+    height: isize,
+}
+
+#[derive(Debug, Clone, Copy)]
+struct Robot {
+    position: Coordinate,
+    velocity: Velocity,
+}
+
+#[derive(Debug, PartialEq, Eq, Hash, Clone, Copy)]
+struct Coordinate {
+    x: isize,
+    y: isize,
+}
+
+#[derive(Debug, Clone, Copy)]
+struct Velocity {
+    dx: isize,
+    dy: isize,
+}
+
+pub fn day_14_part_1() -> usize {
+    let input = read_input();
+
+    let time = 100;
+
+    let safety_factor = calculate_safety_factor_at_time(&input, time);
+
+    println!("{}", render_board_at_time(&input, time));
+
+    println!("Safety factor: {}", safety_factor);
+
+    return safety_factor;
+}
+
+/// Robots move along x and y independently, so their x positions repeat every
+/// `width` seconds and their y positions every `height`. The picture forms
+/// when both are bunched up the most, which is one time modulo each side, and
+/// the Chinese remainder theorem gives the second those meet.
+pub fn day_14_part_2(context: &Context) -> Result<isize, Cancelled> {
+    let input = &read_input();
+    let FloorDimensions { width, height } = input.floor;
+
+    let x_time = most_clustered_time(context, &input.robots, width, |robot, time| {
+        return calculate_robot_position_after_time(robot, time, &input.floor).x;
+    })?;
+    let y_time = most_clustered_time(context, &input.robots, height, |robot, time| {
+        return calculate_robot_position_after_time(robot, time, &input.floor).y;
+    })?;
+    context.finish_progress();
+
+    let (time, _) = chinese_remainder(&[
+        (x_time as i128, width as i128),
+        (y_time as i128, height as i128),
+    ])
+    .expect("the floor's width and height should be coprime");
+    let time = time as isize;
+
+    println!(
+        "robots bunch up along x at {} and along y at {} seconds, both at {} seconds",
+        x_time, y_time, time
+    );
+    println!("{}", render_board_at_time(input, time));
+
+    return Ok(time);
+}
+
+/// The time in `0..period` when the robots' positions along one axis are the
+/// least spread out, measured as the variance times the robot count squared.
+fn most_clustered_time(
+    context: &Context,
+    robots: &Vec<Robot>,
+    period: isize,
+    position_at: impl Fn(&Robot, isize) -> isize,
+) -> Result<isize, Cancelled> {
+    let mut best_time = 0;
+    let mut lowest_spread = i128::MAX;
+
+    for time in 0..period {
+        context.check()?;
+        context.progress(time as usize, period as usize);
+
+        let positions = robots
+            .iter()
+            .map(|robot| position_at(robot, time) as i128)
+            .collect::<Vec<i128>>();
+        let sum = positions.iter().sum::<i128>();
+        let sum_of_squares = positions
+            .iter()
+            .map(|position| position * position)
+            .sum::<i128>();
+        let spread = positions.len() as i128 * sum_of_squares - sum * sum;
+        if spread < lowest_spread {
+            lowest_spread = spread;
+            best_time = time;
+        }
+    }
+
+    return Ok(best_time);
+}
+
+/// Each tick every robot takes one step, wrapping around the floor's edges.
+impl Simulation for FloorDescription {
+    type State = Vec<Robot>;
+    type Key = Vec<Coordinate>;
+
+    fn initial_state(&self) -> Vec<Robot> {
+        return self.robots.clone();
+    }
+
+    fn step(&self, robots: &mut Vec<Robot>) {
+        for robot in robots {
+            robot.position = calculate_robot_position_after_time(robot, 1, &self.floor);
+        }
+    }
+
+    fn render(&self, robots: &Vec<Robot>) -> String {
+        let mut floor_string = String::new();
+        for y in 0..self.floor.height {
+            for x in 0..self.floor.width {
+                let current_coordinate = Coordinate { x, y };
+                let robots_at_coordinate = robots
+                    .iter()
+                    .filter(|robot| robot.position == current_coordinate)
+                    .count();
+
+                if robots_at_coordinate == 0 {
+                    floor_string += ".";
+                    continue;
+                }
+
+                floor_string += &format!("{robots_at_coordinate}");
+            }
+            floor_string += "\n";
+        }
+
+        return floor_string;
+    }
+
+    fn state_key(&self, robots: &Vec<Robot>) -> Vec<Coordinate> {
+        return robots.iter().map(|robot| robot.position).collect();
+    }
+}
+
+fn calculate_safety_factor_at_time(input: &FloorDescription, time: isize) -> usize {
+    let robots = Driver::new(input).run(time as usize).state;
+
+    let (quad_1, quad_2, quad_3, quad_4) = count_robots_in_quadrants(&FloorDescription {
+        robots,
+        floor: input.floor,
+    });
+
+    let safety_factor = quad_1 * quad_2 * quad_3 * quad_4;
+
+    return safety_factor;
+}
+
+fn render_board_at_time(input: &FloorDescription, time: isize) -> String {
+    let robots = Driver::new(input).run(time as usize).state;
+
+    return input.render(&robots);
+}
+
+fn calculate_robot_position_after_time(
+    robot: &Robot,
+    time: isize,
+    floor: &FloorDimensions,
+) -> Coordinate {
+    let x = (robot.position.x + (robot.velocity.dx * time)).rem_euclid(floor.width);
+    let y = (robot.position.y + (robot.velocity.dy * time)).rem_euclid(floor.height);
+
+    return Coordinate { x, y };
+}
+
+fn count_robots_in_quadrants(input: &FloorDescription) -> (usize, usize, usize, usize) {
+    let mut quadrant_1 = 0;
+    let mut quadrant_2 = 0;
+    let mut quadrant_3 = 0;
+    let mut quadrant_4 = 0;
+
+    for robot in &input.robots {
+        if robot.position.x * 2 == input.floor.width - 1
+            || robot.position.y * 2 == input.floor.height - 1
+        {
+            continue;
+        }
+        let is_right = robot.position.x * 2 > input.floor.width;
+        let is_down = robot.position.y * 2 > input.floor.height;
+
+        if is_right && !is_down {
+            quadrant_1 += 1;
+        }
+        if !is_right && !is_down {
+            quadrant_2 += 1;
+        }
+        if !is_right && is_down {
+            quadrant_3 += 1;
+        }
+        if is_right && is_down {
+            quadrant_4 += 1;
+        }
+    }
+
+    return (quadrant_1, quadrant_2, quadrant_3, quadrant_4);
+}
+
+#[test]
+fn example_floor_after_100_seconds_snapshot() {
+    let input = parse_floor_description(
+        &input::read_day_file(2024, 14, "example.txt"),
+        &input::read_day_file(2024, 14, "example_size.txt"),
+    );
+
+    assert_eq!(calculate_safety_factor_at_time(&input, 100), 12);
+    crate::snapshot::assert_snapshot(
+        2024,
+        14,
+        "example_after_100_seconds",
+        &render_board_at_time(&input, 100),
+    );
+}
+
+#[test]
+fn example_robots_repeat_every_77_seconds() {
+    let input = parse_floor_description(
+        &input::read_day_file(2024, 14, "example.txt"),
+        &input::read_day_file(2024, 14, "example_size.txt"),
+    );
+
+    let run = Driver::new(&input).with_cycle_detection().run(usize::MAX);
+
+    // 11 by 7 wraps every 77 seconds, the example's robots don't repeat sooner.
+    assert_eq!(
+        run.ending,
+        crate::simulation::Ending::Cycle {
+            start: 0,
+            length: 77
+        }
+    );
+}
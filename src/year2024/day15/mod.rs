@@ -1,7 +1,11 @@
-use std::{collections::HashSet, fs};
+use std::collections::HashSet;
 
 use peroxide::fuga::max;
-use regex::{Match, Regex};
+
+use crate::{
+    input, parse,
+    simulation::{Driver, Simulation},
+};
 
 #[derive(PartialEq, Debug)]
 enum Direction {
@@ -34,25 +38,28 @@ struct WarehouseTile {
 }
 
 fn read_input() -> (Vec<WarehouseTile>, Vec<Direction>) {
-    let input = fs::read_to_string("src/day15/input.txt").expect("No input for day 15");
+    return parse_warehouse(&input::read_input(2024, 15));
+}
 
-    let map_regex = Regex::new(r"[#.O@]+").unwrap();
-    let moves_regex = Regex::new(r"[<>^v]").unwrap();
+fn parse_warehouse(input: &str) -> (Vec<WarehouseTile>, Vec<Direction>) {
+    let sections = parse::sections(input);
+    let [map_lines, move_lines] = &sections[..] else {
+        panic!("day 15 input should be a map and moves separated by a blank line");
+    };
 
-    let moves = moves_regex
-        .find_iter(&input)
-        .map(|direction| map_char_to_direction(direction.as_str()))
-        .collect::<Vec<Direction>>();
+    let map =
+        parse::grid_of_lines(map_lines).unwrap_or_else(|error| panic!("day 15 input: {error}"));
 
-    let warehouse_floor_matches = map_regex.find_iter(&input).collect::<Vec<Match>>();
+    let moves = move_lines
+        .iter()
+        .flat_map(|line| line.text.chars())
+        .map(map_char_to_direction)
+        .collect::<Vec<Direction>>();
 
     let mut warehouse_floor = vec![];
 
-    for y in 0..warehouse_floor_matches.len() {
-        let line = warehouse_floor_matches[y]
-            .as_str()
-            .chars()
-            .collect::<Vec<char>>();
+    for y in 0..map.len() {
+        let line = &map[y];
         for x in 0..line.len() {
             let char = line[x];
             warehouse_floor.push(WarehouseTile {
@@ -90,12 +97,12 @@ fn double_width_on_input(input: Vec<WarehouseTile>) -> Vec<WarehouseTile> {
     return new_floor;
 }
 
-fn map_char_to_direction(char: &str) -> Direction {
+fn map_char_to_direction(char: char) -> Direction {
     match char {
-        "<" => Direction::Left,
-        "^" => Direction::Up,
-        ">" => Direction::Right,
-        "v" => Direction::Down,
+        '<' => Direction::Left,
+        '^' => Direction::Up,
+        '>' => Direction::Right,
+        'v' => Direction::Down,
         _ => panic!("Invalid Direction Char"),
     }
 }
@@ -430,7 +437,7 @@ fn find_last_coordinate_in_slice_in_direction(
     }
 }
 
-fn print_board(floor: &Vec<WarehouseTile>) {
+fn render_board(floor: &Vec<WarehouseTile>) -> String {
     let mut board_string = String::new();
     let mut max_x = 0;
     let mut y = 0;
@@ -462,58 +469,139 @@ fn print_board(floor: &Vec<WarehouseTile>) {
         }
         y += 1;
         let current_coordinate = Coordinate { x: 0, y };
+        board_string.push('\n');
         if let None = floor
             .iter()
             .find(|tile| tile.coordinate == current_coordinate)
         {
             break;
         }
-        board_string.push('\n');
     }
 
-    println!("{board_string}");
+    return board_string;
 }
 
 fn calculate_gps_coordinate(coordinate: Coordinate) -> usize {
     return coordinate.y * 100 + coordinate.x;
 }
 
-fn calculate_total_gps_score(floor: &Vec<WarehouseTile>) {
+fn calculate_total_gps_score(floor: &Vec<WarehouseTile>) -> usize {
     let boxes = floor
         .iter()
         .filter(|tile| tile.contents == TileType::Box || tile.contents == TileType::BoxLeft)
         .map(|tile| calculate_gps_coordinate(tile.coordinate))
         .sum::<usize>();
 
-    println!("Total gps score: {}", boxes)
+    println!("Total gps score: {}", boxes);
+
+    return boxes;
 }
 
-pub fn day_15_part_1() {
-    let (mut floor, moves) = read_input();
+/// The robot working through its list of moves, one per tick.
+struct Warehouse {
+    floor: Vec<WarehouseTile>,
+    moves: Vec<Direction>,
+    wide: bool,
+}
+
+#[derive(Clone)]
+struct WarehouseState {
+    floor: Vec<WarehouseTile>,
+    next_move: usize,
+}
+
+impl Simulation for Warehouse {
+    type State = WarehouseState;
+    type Key = (Vec<WarehouseTile>, usize);
+
+    fn initial_state(&self) -> WarehouseState {
+        return WarehouseState {
+            floor: self.floor.clone(),
+            next_move: 0,
+        };
+    }
+
+    fn step(&self, state: &mut WarehouseState) {
+        let direction = &self.moves[state.next_move];
+        if self.wide {
+            move_robot_in_direction_wide(&mut state.floor, direction);
+        } else {
+            move_robot_in_direction(&mut state.floor, direction);
+        }
+        state.next_move += 1;
+    }
 
-    print_board(&floor);
+    fn is_terminal(&self, state: &WarehouseState) -> bool {
+        return state.next_move == self.moves.len();
+    }
 
-    for direction in moves {
-        move_robot_in_direction(&mut floor, &direction);
-        // print_board(&floor);
+    fn render(&self, state: &WarehouseState) -> String {
+        return render_board(&state.floor);
     }
 
-    print_board(&floor);
-    calculate_total_gps_score(&floor);
+    fn state_key(&self, state: &WarehouseState) -> (Vec<WarehouseTile>, usize) {
+        return (state.floor.clone(), state.next_move);
+    }
 }
 
-pub fn day_15_part_2() {
-    let (mut floor, moves) = read_input();
+/// Runs every move and prints the floor before and after.
+fn run_warehouse(warehouse: &Warehouse) -> Vec<WarehouseTile> {
+    let run = Driver::new(warehouse)
+        .render_every(warehouse.moves.len(), |_, board| println!("{board}"))
+        .run(usize::MAX);
+
+    return run.state.floor;
+}
 
-    floor = double_width_on_input(floor);
+pub fn day_15_part_1() -> usize {
+    let (floor, moves) = read_input();
 
-    print_board(&floor);
+    let floor = run_warehouse(&Warehouse {
+        floor,
+        moves,
+        wide: false,
+    });
 
-    for direction in moves {
-        move_robot_in_direction_wide(&mut floor, &direction);
-        // print_board(&floor);
-    }
+    return calculate_total_gps_score(&floor);
+}
+
+pub fn day_15_part_2() -> usize {
+    let (floor, moves) = read_input();
+
+    let floor = run_warehouse(&Warehouse {
+        floor: double_width_on_input(floor),
+        moves,
+        wide: true,
+    });
+
+    return calculate_total_gps_score(&floor);
+}
+
+#[test]
+fn example_warehouse_after_moves_snapshot() {
+    let (floor, moves) = parse_warehouse(&input::read_day_file(2024, 15, "example.txt"));
+    let floor = run_warehouse(&Warehouse {
+        floor,
+        moves,
+        wide: false,
+    });
+
+    assert_eq!(calculate_total_gps_score(&floor), 10092);
+    crate::snapshot::assert_snapshot(2024, 15, "example_after_moves", &render_board(&floor));
+}
 
-    print_board(&floor);
-    calculate_total_gps_score(&floor);
+#[test]
+fn example_wide_warehouse_after_moves_snapshot() {
+    let (floor, moves) = parse_warehouse(&input::read_day_file(2024, 15, "example.txt"));
+    let warehouse = Warehouse {
+        floor: double_width_on_input(floor),
+        moves,
+        wide: true,
+    };
+    let run = Driver::new(&warehouse).with_history().run(usize::MAX);
+    let replay = crate::simulation::replay(&warehouse, &run);
+    crate::snapshot::assert_snapshot(2024, 15, "example_wide_start", &replay[0]);
+
+    assert_eq!(calculate_total_gps_score(&run.state.floor), 9021);
+    crate::snapshot::assert_snapshot(2024, 15, "example_wide_after_moves", replay.last().unwrap());
 }
@@ -1,7 +1,9 @@
-use std::{collections::HashMap, fs, usize};
+use std::{collections::HashMap, usize};
 
 use strum::{EnumIter, IntoEnumIterator};
 
+use crate::{input, metrics, parse};
+
 #[derive(EnumIter, PartialEq, Clone, Copy)]
 enum Direction {
     North,
@@ -63,9 +65,12 @@ impl Maze {
 }
 
 fn read_input() -> Maze {
-    let input = fs::read_to_string("src/day16/input.txt").expect("Day 16 input not present");
+    return parse_maze(&input::read_input(2024, 16));
+}
 
-    let parsed_map = input.lines().map(|line| line.chars().collect()).collect();
+fn parse_maze(input: &str) -> Maze {
+    let parsed_map =
+        parse::char_grid(input).unwrap_or_else(|error| panic!("day 16 input: {error}"));
 
     return Maze(parsed_map);
 }
@@ -137,7 +142,8 @@ fn find_possible_next_moves(
     return moves;
 }
 
-fn print_board(input: &Maze) {
+#[cfg(test)]
+fn render_board(input: &Maze) -> String {
     let mut string_to_print = String::new();
 
     for y in 0..input.columns() {
@@ -147,9 +153,21 @@ fn print_board(input: &Maze) {
         string_to_print.push('\n');
     }
 
-    println!("{}", string_to_print);
+    return string_to_print;
+}
+
+/// The maze with the moose's heading drawn on every tile of `path`.
+#[cfg(test)]
+fn render_path(input: &Maze, path: &HashMap<Coordinate, Move>) -> String {
+    let mut input = input.clone();
+    for movement in path.values() {
+        move_moose(&mut input, *movement);
+    }
+
+    return render_board(&input);
 }
 
+#[cfg(test)]
 fn move_moose(input: &mut Maze, movement: Move) {
     match movement.direction {
         Direction::North => input.insert_at_coordinate(&movement.coordinate, '^'),
@@ -234,14 +252,6 @@ fn find_possible_paths(input: &mut Maze) -> Vec<HashMap<Coordinate, Move>> {
         &mut distance_matrix,
     );
 
-    for successhul_path in successful_paths.clone() {
-        let mut input = input.clone();
-        for (_, movement) in successhul_path {
-            move_moose(&mut input, movement);
-        }
-        print_board(&input);
-    }
-
     return successful_paths;
 }
 
@@ -293,6 +303,8 @@ fn find_optimal_path(input: &mut Maze) -> usize {
     score_map.entry(start).insert_entry(first_move);
 
     let mut moves_to_evaluate = get_next_moves(&first_move);
+    let mut nodes_expanded = 0;
+    let mut heap_pushes = moves_to_evaluate.len();
 
     let mut current_min = usize::MAX;
 
@@ -301,6 +313,7 @@ fn find_optimal_path(input: &mut Maze) -> usize {
         if character == '#' {
             continue;
         }
+        nodes_expanded += 1;
 
         if character == 'E' && move_to_evaluate.points <= current_min {
             current_min = move_to_evaluate.points;
@@ -316,20 +329,46 @@ fn find_optimal_path(input: &mut Maze) -> usize {
                 .or_insert(possible_next_move);
 
             if score_at_coordinate.points == possible_next_move.points {
+                heap_pushes += 1;
                 moves_to_evaluate.push(possible_next_move);
                 moves_to_evaluate.sort_by(|move_a, move_b| move_b.points.cmp(&move_a.points));
             }
         }
     }
+    metrics::add("nodes expanded", nodes_expanded);
+    metrics::add("heap pushes", heap_pushes);
+
     return current_min;
 }
 
-pub fn day_16_part_1() {
+pub fn day_16_part_1() -> usize {
     let mut input = read_input();
 
     // let paths = find_possible_paths(&mut input);
 
     let lowest_score = find_optimal_path(&mut input);
 
-    println!("min score: {}", lowest_score)
+    println!("min score: {}", lowest_score);
+
+    return lowest_score;
+}
+
+#[test]
+fn example_lowest_scoring_path_snapshot() {
+    let mut input = parse_maze(&input::read_day_file(2024, 16, "example.txt"));
+
+    let paths = find_possible_paths(&mut input);
+    let best_path = paths
+        .iter()
+        .min_by_key(|path| calculate_score((*path).clone()))
+        .unwrap();
+
+    assert_eq!(calculate_score(best_path.clone()), 7036);
+    assert_eq!(find_optimal_path(&mut input), 7036);
+    crate::snapshot::assert_snapshot(
+        2024,
+        16,
+        "example_lowest_scoring_path",
+        &render_path(&input, best_path),
+    );
 }
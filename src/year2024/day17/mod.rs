@@ -1,6 +1,4 @@
-use std::fs;
-
-use regex::Regex;
+use crate::{input, parse};
 
 #[derive(Debug)]
 struct Computer {
@@ -13,40 +11,32 @@ struct Computer {
 }
 
 fn read_input() -> Computer {
-    let input = fs::read_to_string("src/day17/input.txt").expect("error reading day 17 input");
-    let mut input = input.lines();
-
-    let register_a = input.next().unwrap();
-    let register_b = input.next().unwrap();
-    let register_c = input.next().unwrap();
-    input.next();
-    let program = input.next().unwrap();
-
-    let number_regex = Regex::new(r"\d+").unwrap();
-
-    let register_a = number_regex
-        .find(register_a)
-        .unwrap()
-        .as_str()
-        .parse::<isize>()
-        .expect("incorrect input format");
-    let register_b = number_regex
-        .find(register_b)
-        .unwrap()
-        .as_str()
-        .parse::<isize>()
-        .expect("incorrect input format");
-    let register_c = number_regex
-        .find(register_c)
-        .unwrap()
-        .as_str()
-        .parse::<isize>()
-        .expect("incorrect input format");
-
-    let program = number_regex
-        .find_iter(program)
-        .map(|number_string| number_string.as_str().parse::<isize>().unwrap())
-        .collect();
+    let input = input::read_input(2024, 17);
+    let integers = parse::sections(&input)
+        .iter()
+        .map(|section| {
+            let mut integers = vec![];
+            for line in section {
+                let (_, value) = line
+                    .key_value(":")
+                    .unwrap_or_else(|error| panic!("day 17 input: {error}"));
+                integers.push(
+                    value
+                        .separated::<isize>(',')
+                        .unwrap_or_else(|error| panic!("day 17 input: {error}")),
+                );
+            }
+            return integers;
+        })
+        .collect::<Vec<Vec<Vec<isize>>>>();
+
+    let [registers, program] = &integers[..] else {
+        panic!("day 17 input should be registers and a program separated by a blank line");
+    };
+    let [register_a, register_b, register_c] = registers.concat()[..] else {
+        panic!("day 17 input should start with registers A, B and C");
+    };
+    let program = program.concat();
 
     return Computer {
         register_a,
@@ -70,15 +60,17 @@ impl Computer {
         }
     }
 
-    fn print_output(&self) {
-        let result = self
+    fn output_string(&self) -> String {
+        return self
             .output
             .iter()
             .map(|numbner| numbner.to_string())
             .collect::<Vec<String>>()
             .join(",");
+    }
 
-        println!("output: {}", result)
+    fn print_output(&self) {
+        println!("output: {}", self.output_string())
     }
 
     fn execute_program(&mut self) {
@@ -173,13 +165,15 @@ impl Computer {
     }
 }
 
-pub fn day_17_part_1() {
+pub fn day_17_part_1() -> String {
     let mut input = read_input();
 
     input.execute_program();
+
+    return input.output_string();
 }
 
-pub fn day_17_part_2() {
+pub fn day_17_part_2() -> isize {
     let mut input = read_input();
 
     let mut register_a = 0;
@@ -207,6 +201,8 @@ pub fn day_17_part_2() {
     }
 
     println!("{}", register_a);
+
+    return register_a;
 }
 
 #[test]
@@ -6,22 +6,30 @@ use std::{
 
 use strum::{EnumIter, IntoEnumIterator};
 
-fn read_input(filename: &str) -> Maze {
-    let input =
-        fs::read_to_string(format!("src/day18/{filename}.txt")).expect("day 18 input missing");
+use crate::{
+    context::{Cancelled, Context},
+    input, metrics, parse,
+};
 
-    let coordinates = input
-        .lines()
+fn read_input(filename: &str) -> Maze {
+    let input = if filename == "input" {
+        input::read_input(2024, 18)
+    } else {
+        fs::read_to_string(format!("src/year2024/day18/{filename}.txt"))
+            .expect("day 18 input missing")
+    };
+
+    let coordinates = parse::lines(&input)
+        .iter()
         .map(|line| {
             let numbers = line
-                .split(',')
-                .map(|number| number.parse::<usize>().unwrap())
-                .collect::<Vec<usize>>();
-
-            return Coordinate {
-                x: numbers[0],
-                y: numbers[1],
+                .separated::<usize>(',')
+                .unwrap_or_else(|error| panic!("day 18 input: {error}"));
+            let [x, y] = numbers[..] else {
+                panic!("day 18 input: {}", line.error(1, "expected x,y"));
             };
+
+            return Coordinate { x, y };
         })
         .collect();
 
@@ -106,19 +114,23 @@ impl Maze {
         }
     }
 
-    fn find_max_available_time(&mut self) -> Coordinate {
+    fn find_max_available_time(&mut self, context: &Context) -> Result<Coordinate, Cancelled> {
         let mut shortest_path = self.dijkstra();
         self.reset();
 
         while shortest_path < usize::MAX {
+            context.check()?;
+            context.progress(self.memory_dropped, self.memory_tiles.len());
+
             self.drop_memory(self.memory_dropped + 1);
             shortest_path = self.dijkstra();
             self.reset();
         }
+        context.finish_progress();
 
         let coordinate_that_blocks = self.memory_tiles[self.memory_dropped - 1];
 
-        return coordinate_that_blocks;
+        return Ok(coordinate_that_blocks);
     }
 
     fn drop_memory(&mut self, count: usize) {
@@ -132,7 +144,7 @@ impl Maze {
         }
     }
 
-    fn draw(&self) {
+    fn render(&self) -> String {
         let mut string = String::new();
 
         for y in 0..=self.max_y {
@@ -148,7 +160,7 @@ impl Maze {
             string += "\n";
         }
 
-        println!("{}", string);
+        return string;
     }
 
     fn dijkstra(&mut self) -> usize {
@@ -161,6 +173,8 @@ impl Maze {
         start.distance = 0;
 
         let mut priority_heap = BinaryHeap::new();
+        let mut nodes_expanded = 0;
+        let mut heap_pushes = 1;
 
         priority_heap.push(Reverse(TileToCheck {
             coordinate: Coordinate { x: 0, y: 0 },
@@ -168,6 +182,7 @@ impl Maze {
         }));
 
         while let Some(Reverse(tile_to_check)) = priority_heap.pop() {
+            nodes_expanded += 1;
             let new_cost = tile_to_check.cost + 1;
             let tiles_to_check =
                 get_next_coordinates(&tile_to_check.coordinate, self.max_y, self.max_x);
@@ -177,6 +192,7 @@ impl Maze {
 
                 if next_tile.tile_type == TileType::Empty && next_tile.distance > new_cost {
                     next_tile.distance = new_cost;
+                    heap_pushes += 1;
                     priority_heap.push(Reverse(TileToCheck {
                         coordinate: tile,
                         cost: new_cost,
@@ -184,6 +200,8 @@ impl Maze {
                 }
             }
         }
+        metrics::add("nodes expanded", nodes_expanded);
+        metrics::add("heap pushes", heap_pushes);
 
         return self.tiles.get(&end).unwrap().distance;
     }
@@ -230,33 +248,36 @@ fn get_next_coordinates(coordinate: &Coordinate, y_max: usize, x_max: usize) ->
     return result;
 }
 
-pub fn day_18_part_1() {
+pub fn day_18_part_1() -> usize {
     let mut maze = read_input("input");
 
     maze.drop_memory(1024);
 
     let shortest_path = maze.dijkstra();
 
-    maze.draw();
+    println!("{}", maze.render());
 
-    println!("shortest path: {}", shortest_path)
+    println!("shortest path: {}", shortest_path);
+
+    return shortest_path;
 }
 
-pub fn day_18_part_2() {
+pub fn day_18_part_2(context: &Context) -> Result<String, Cancelled> {
     let mut maze = read_input("input");
 
     maze.drop_memory(1024);
 
     maze.dijkstra();
 
-    let coordinate_that_blocks = maze.find_max_available_time();
+    let coordinate_that_blocks = maze.find_max_available_time(context)?;
+
+    println!("{}", maze.render());
 
-    maze.draw();
+    let answer = format!("{},{}", coordinate_that_blocks.x, coordinate_that_blocks.y);
 
-    println!(
-        "Coordinate that blocks: {},{}",
-        coordinate_that_blocks.x, coordinate_that_blocks.y
-    )
+    println!("Coordinate that blocks: {}", answer);
+
+    return Ok(answer);
 }
 
 #[test]
@@ -276,7 +297,43 @@ fn example_input_part_2() {
 
     maze.drop_memory(12);
 
-    let coordinate_that_blocks = maze.find_max_available_time();
+    let coordinate_that_blocks = maze.find_max_available_time(&Context::new()).unwrap();
 
     assert_eq!(coordinate_that_blocks, Coordinate { x: 6, y: 1 })
 }
+
+#[test]
+fn example_input_part_2_stops_when_cancelled() {
+    let mut maze = read_input("example");
+
+    maze.drop_memory(12);
+
+    let context = Context::new();
+    context.cancel();
+
+    assert_eq!(maze.find_max_available_time(&context), Err(Cancelled));
+}
+
+#[test]
+fn example_memory_after_12_bytes_snapshot() {
+    let mut maze = read_input("example");
+
+    maze.drop_memory(12);
+
+    crate::snapshot::assert_snapshot(2024, 18, "example_after_12_bytes", &maze.render());
+}
+
+#[test]
+fn example_dijkstra_counts_expanded_nodes_and_heap_pushes() {
+    let mut maze = read_input("example");
+
+    maze.drop_memory(12);
+
+    let (shortest_path, counters) = metrics::measure(|| maze.dijkstra());
+
+    assert_eq!(shortest_path, 22);
+    assert_eq!(counters.len(), 2);
+    assert!(counters.iter().all(|(_, value)| *value > 0));
+    // The heap is drained, so every push is popped and expanded once.
+    assert_eq!(counters[0].1, counters[1].1);
+}
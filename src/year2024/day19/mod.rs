@@ -1,8 +1,7 @@
-use std::
-    fs
-;
+use crate::{input, memo::Memo, metrics, parse};
 
 fn can_be_made_from_segments(segments: &Vec<String>, string_to_build: &str) -> bool {
+    metrics::increment("recursion calls");
     for segment in segments.clone() {
         if string_to_build.starts_with(&segment) {
             let (_, rest_of_string) = string_to_build.split_at(segment.len());
@@ -26,94 +25,51 @@ fn can_be_made_from_segments(segments: &Vec<String>, string_to_build: &str) -> b
 }
 
 fn count_number_of_solutions(segments: &Vec<String>, string_to_build: &str) -> usize {
-    let mut number_of_solutions = 0;
-    for segment in segments.clone() {
-        if string_to_build.starts_with(&segment) {
-            let (_, rest_of_string) = string_to_build.split_at(segment.len());
-            if rest_of_string.is_empty() {
-                number_of_solutions += 1;
-            }
-
-            let valid_segments = segments
-                .iter()
-                .map(|segment| segment.clone())
-                .filter(|segment| rest_of_string.contains(segment))
-                .collect::<Vec<String>>();
-
-            number_of_solutions += count_number_of_solutions(&valid_segments, rest_of_string);
-        }
-    }
-
-    return number_of_solutions;
+    return count_solutions_for_rest(&mut Memo::new(), segments, string_to_build);
 }
 
-fn count_number_of_solutions_rewrite(segments: &Vec<String>, strings_to_build: Vec<&str>) -> usize {
-    let mut total = 0;
-
-    for string_to_build in strings_to_build {
-        total += sort_towels(segments, string_to_build);
-    }
-
-    return total;
-}
-
-fn sort_towels(segments: &Vec<String>, string_to_build: &str) -> usize {
-    let mut segments_used = vec![vec![]; string_to_build.len()];
-
-    for segment in segments {
-        if string_to_build.contains(segment) {
-            let mut last_index = 0;
-
-            while string_to_build[last_index..].contains(segment) {
-                let index = string_to_build[last_index..].find(segment).unwrap() + last_index;
-
-                segments_used[index].push(segment.clone());
-
-                last_index = index + 1;
-            }
-        }
-    }
-
-    let mut possibilities = vec![0; string_to_build.len()];
-
-    for index in (0..segments_used.len()).rev() {
-        for possible_segments_at_index in &segments_used[index] {
-            if index + possible_segments_at_index.len() < string_to_build.len() {
-                possibilities[index] += possibilities[index + possible_segments_at_index.len()];
-            } else {
-                possibilities[index] += 1;
-            }
-            println!("{:?}", possibilities);
-        }
-    }
-
-    if *possibilities.first().unwrap() > 0 {
-    } else {
+/// The count only depends on what is left to build, so it's cached by that.
+fn count_solutions_for_rest<'a>(
+    memo: &mut Memo<&'a str, usize>,
+    segments: &Vec<String>,
+    string_to_build: &'a str,
+) -> usize {
+    if string_to_build.is_empty() {
+        return 1;
     }
 
-    return *possibilities.first().unwrap();
+    return memo.get_or_compute(string_to_build, |memo| {
+        return segments
+            .iter()
+            .filter_map(|segment| string_to_build.strip_prefix(segment.as_str()))
+            .map(|rest_of_string| count_solutions_for_rest(memo, segments, rest_of_string))
+            .sum();
+    });
 }
 
 fn read_input() -> (Vec<String>, Vec<String>) {
-    let input = fs::read_to_string("src/day19/input.txt").expect("input for day 19 not present");
+    let input = input::read_input(2024, 19);
 
-    let mut input = input.lines();
+    let sections = parse::sections(&input);
+    let [segment_lines, string_lines] = &sections[..] else {
+        panic!("day 19 input should be towels and designs separated by a blank line");
+    };
 
-    let segments = input
-        .next()
-        .unwrap()
-        .split(", ")
-        .map(|element| element.to_string())
+    let segments = segment_lines
+        .iter()
+        .flat_map(|line| line.text.split(','))
+        .map(|element| element.trim().to_string())
         .collect();
 
-    input.next();
-
-    let strings_to_build = input.map(|element| element.to_string()).collect();
+    let strings_to_build = string_lines
+        .iter()
+        .map(|line| line.text.to_string())
+        .collect();
 
     return (segments, strings_to_build);
 }
 
-pub fn day_19_part_1() {
+pub fn day_19_part_1() -> usize {
     let (segments, strings_to_build) = read_input();
 
     let mut total_possible = 0;
@@ -131,16 +87,18 @@ pub fn day_19_part_1() {
         }
     }
 
-    println!("total possible combinations: {}", total_possible)
+    println!("total possible combinations: {}", total_possible);
+
+    return total_possible;
 }
 
-pub fn day_19_part_2() {
+pub fn day_19_part_2() -> usize {
     let (segments, strings_to_build) = read_input();
 
-    let total = count_number_of_solutions_rewrite(
-        &segments,
-        strings_to_build.iter().map(|str| str.as_str()).collect(),
-    );
+    let total = strings_to_build
+        .iter()
+        .map(|string_to_build| count_number_of_solutions(&segments, string_to_build))
+        .sum::<usize>();
 
     println!("{}", total);
 
@@ -175,6 +133,8 @@ pub fn day_19_part_2() {
     //     "total possible solutions: {}",
     //     total_possible.load(std::sync::atomic::Ordering::SeqCst)
     // )
+
+    return total;
 }
 
 #[test]
@@ -258,19 +218,3 @@ fn example_part_2() {
 
     assert_eq!(total_solutions, 16);
 }
-
-#[test]
-fn example_part_2_rewrite() {
-    let segments = vec!["r", "wr", "b", "g", "bwu", "rb", "gb", "br"]
-        .iter()
-        .map(|value| value.to_string())
-        .collect();
-
-    let strings_to_build = vec![
-        "brwrr", "bggr", "gbbr", "rrbgbr", "ubwu", "bwurrg", "brgr", "bbrgwb",
-    ];
-
-    let total_solutions = count_number_of_solutions_rewrite(&segments, strings_to_build);
-
-    assert_eq!(total_solutions, 16);
-}
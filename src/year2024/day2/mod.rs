@@ -0,0 +1,789 @@
+use std::fmt;
+
+use crate::{
+    input,
+    parse::{self, ParseError},
+};
+
+pub fn day_2() -> (usize, usize) {
+    let file = input::read_input(2024, 2);
+    let part_1 = SafetyPolicy::default();
+    let part_2 = part_1.with_removals(1);
+
+    let mut number_of_safe_reports_part_1 = 0;
+    let mut number_of_safe_reports_part_2 = 0;
+
+    for report in parse::lines(&file) {
+        let readings: Vec<i32> = report
+            .whitespace_separated()
+            .unwrap_or_else(|error| panic!("day 2 input: {error}"));
+
+        if part_1.is_safe(&readings) {
+            number_of_safe_reports_part_1 += 1;
+        }
+
+        if part_2.is_safe(&readings) {
+            number_of_safe_reports_part_2 += 1;
+        }
+    }
+
+    println!("{number_of_safe_reports_part_1} {number_of_safe_reports_part_2}");
+
+    return (number_of_safe_reports_part_1, number_of_safe_reports_part_2);
+}
+
+/// Which way the readings have to move.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Trend {
+    Increasing,
+    Decreasing,
+    /// All increasing or all decreasing, whichever fits.
+    Monotonic,
+    /// Steps may go either way.
+    Any,
+}
+
+/// What makes a report safe. The default is the puzzle's: all increasing or
+/// all decreasing, by 1 to 3 at a time, without removing anything.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct SafetyPolicy {
+    pub min_step: i32,
+    pub max_step: i32,
+    pub trend: Trend,
+    /// How many readings the dampener may remove.
+    pub removals: usize,
+}
+
+impl Default for SafetyPolicy {
+    fn default() -> Self {
+        return SafetyPolicy {
+            min_step: 1,
+            max_step: 3,
+            trend: Trend::Monotonic,
+            removals: 0,
+        };
+    }
+}
+
+impl SafetyPolicy {
+    pub fn with_steps(mut self, min_step: i32, max_step: i32) -> Self {
+        self.min_step = min_step;
+        self.max_step = max_step;
+        return self;
+    }
+
+    pub fn with_trend(mut self, trend: Trend) -> Self {
+        self.trend = trend;
+        return self;
+    }
+
+    pub fn with_removals(mut self, removals: usize) -> Self {
+        self.removals = removals;
+        return self;
+    }
+
+    pub fn is_safe(&self, readings: &[i32]) -> bool {
+        return self.fewest_removals(readings).is_some();
+    }
+
+    /// The fewest readings to remove to make the report safe, `None` when it
+    /// takes more than the policy allows. Reports of one reading or none are
+    /// always safe.
+    pub fn fewest_removals(&self, readings: &[i32]) -> Option<usize> {
+        let fewest = match self.trend {
+            Trend::Monotonic => self
+                .fewest_removals_with(readings, Trend::Increasing)
+                .min(self.fewest_removals_with(readings, Trend::Decreasing)),
+            trend => self.fewest_removals_with(readings, trend),
+        };
+
+        return Some(fewest).filter(|fewest| *fewest <= self.removals);
+    }
+
+    /// The fewest readings whose values have to change, rather than be
+    /// removed, to make the report safe, with one way of changing them. Changed
+    /// readings stay within `i32`. `None` when no report of this length is
+    /// safe, e.g. because the minimum step is above the maximum. The policy's
+    /// removals don't matter here.
+    pub fn cheapest_repair(&self, readings: &[i32]) -> Option<Repair> {
+        match self.trend {
+            Trend::Monotonic => {
+                let increasing = self.repair_with(readings, Trend::Increasing);
+                let decreasing = self.repair_with(readings, Trend::Decreasing);
+                return match (increasing, decreasing) {
+                    (Some(increasing), Some(decreasing)) => {
+                        if decreasing.changes < increasing.changes {
+                            Some(decreasing)
+                        } else {
+                            Some(increasing)
+                        }
+                    }
+                    (increasing, decreasing) => increasing.or(decreasing),
+                };
+            }
+            trend => return self.repair_with(readings, trend),
+        }
+    }
+
+    /// The steps allowed going in `trend`, which isn't `Monotonic`.
+    fn step_ranges(&self, trend: Trend) -> Ranges {
+        let (min, max) = (self.min_step as i64, self.max_step as i64);
+        let steps = match trend {
+            Trend::Increasing => vec![(min, max)],
+            Trend::Decreasing => vec![(-max, -min)],
+            Trend::Monotonic | Trend::Any => vec![(-max, -min), (min, max)],
+        };
+
+        return normalize(steps, (i64::MIN, i64::MAX));
+    }
+
+    /// `reachable[index][changes]` are the values reading `index` can take
+    /// when the readings up to it are safe with at most `changes` of them
+    /// changed. Keeping a reading needs it among the values one step on from
+    /// the previous ones, changing it allows all of them. The ranges are kept
+    /// to `i32`, so a repair never needs a value that doesn't fit. Walking
+    /// back from the last reading then picks one value per reading.
+    fn repair_with(&self, readings: &[i32], trend: Trend) -> Option<Repair> {
+        let length = readings.len();
+        if length == 0 {
+            return Some(Repair {
+                changes: 0,
+                repaired: vec![],
+            });
+        }
+
+        let steps = self.step_ranges(trend);
+        let all = vec![(i32::MIN as i64, i32::MAX as i64)];
+        let first = readings[0] as i64;
+        let mut reachable: Vec<Vec<Ranges>> = vec![(0..=length)
+            .map(|changes| match changes {
+                0 => vec![(first, first)],
+                _ => all.clone(),
+            })
+            .collect()];
+
+        for index in 1..length {
+            let reading = readings[index] as i64;
+            let previous = &reachable[index - 1];
+            let row = (0..=length)
+                .map(|changes| {
+                    let mut ranges = vec![];
+                    if contains(&shifted(&previous[changes], &steps), reading) {
+                        ranges.push((reading, reading));
+                    }
+                    if changes > 0 {
+                        ranges.extend(shifted(&previous[changes - 1], &steps));
+                    }
+                    return normalize(ranges, all[0]);
+                })
+                .collect();
+            reachable.push(row);
+        }
+
+        let changes = (0..=length).find(|changes| !reachable[length - 1][*changes].is_empty())?;
+
+        let mut budget = changes;
+        let mut value = closest(&reachable[length - 1][budget], readings[length - 1] as i64);
+        let mut repaired = vec![0; length];
+        for index in (0..length).rev() {
+            repaired[index] = value as i32;
+            if index == 0 {
+                break;
+            }
+
+            let before = steps
+                .iter()
+                .map(|(low, high)| (value - high, value - low))
+                .collect::<Ranges>();
+            let kept = intersect(&reachable[index - 1][budget], &before);
+            let wanted = readings[index - 1] as i64;
+            value = if value == readings[index] as i64 && !kept.is_empty() {
+                closest(&kept, wanted)
+            } else {
+                budget -= 1;
+                closest(&intersect(&reachable[index - 1][budget], &before), wanted)
+            };
+        }
+
+        return Some(Repair { changes, repaired });
+    }
+
+    /// Why the report breaks the policy's step and trend rules as it is, and
+    /// which single readings could be removed to fix it. The policy's own
+    /// removals don't matter here.
+    pub fn diagnose(&self, readings: &[i32]) -> Diagnosis {
+        // A monotonic report goes the way its first non-zero step went, 0 lets
+        // every step go either way.
+        let direction = match self.trend {
+            Trend::Increasing => 1,
+            Trend::Decreasing => -1,
+            Trend::Monotonic => readings
+                .windows(2)
+                .map(|pair| pair[1] as i64 - pair[0] as i64)
+                .find(|step| *step != 0)
+                .unwrap_or(0)
+                .signum(),
+            Trend::Any => 0,
+        };
+        let first_violation = (1..readings.len()).find_map(|index| {
+            return self
+                .broken_rule(readings, index, direction)
+                .map(|rule| RuleViolation { index, rule });
+        });
+
+        return Diagnosis {
+            safe: self.with_removals(0).is_safe(readings),
+            first_violation,
+            safe_removals: self.safe_removals(readings),
+        };
+    }
+
+    /// The rule the step from `readings[index - 1]` to `readings[index]`
+    /// breaks when the report has to go in `direction`.
+    fn broken_rule(&self, readings: &[i32], index: usize, direction: i64) -> Option<Rule> {
+        let step = readings[index] as i64 - readings[index - 1] as i64;
+        if step == 0 && self.min_step > 0 {
+            return Some(Rule::ZeroStep);
+        }
+        if step.signum() * direction < 0 {
+            return Some(Rule::DirectionFlip);
+        }
+
+        if step.abs() > self.max_step as i64 {
+            return Some(Rule::StepTooBig);
+        }
+        if step.abs() < self.min_step as i64 {
+            return Some(Rule::StepTooSmall);
+        }
+        return None;
+    }
+
+    /// The readings whose removal alone makes the report safe. Removing one
+    /// leaves the readings before it, those after it and the step bridging
+    /// it, so with which prefixes and suffixes are safe worked out once this
+    /// takes `O(n)`.
+    fn safe_removals(&self, readings: &[i32]) -> Vec<usize> {
+        let trends = match self.trend {
+            Trend::Monotonic => vec![Trend::Increasing, Trend::Decreasing],
+            trend => vec![trend],
+        };
+        let length = readings.len();
+        let mut safe = vec![false; length];
+
+        for trend in trends {
+            let allowed =
+                |from: usize, to: usize| self.is_allowed_step(readings[from], readings[to], trend);
+
+            // `safe_before[index]` is whether readings `..index` are safe,
+            // `safe_from[index]` whether readings `index..` are.
+            let mut safe_before = vec![true; length + 1];
+            for index in 2..=length {
+                safe_before[index] = safe_before[index - 1] && allowed(index - 2, index - 1);
+            }
+            let mut safe_from = vec![true; length + 1];
+            for index in (0..length.saturating_sub(1)).rev() {
+                safe_from[index] = safe_from[index + 1] && allowed(index, index + 1);
+            }
+
+            for removed in 0..length {
+                let bridged =
+                    removed == 0 || removed + 1 == length || allowed(removed - 1, removed + 1);
+                if safe_before[removed] && safe_from[removed + 1] && bridged {
+                    safe[removed] = true;
+                }
+            }
+        }
+
+        return (0..length).filter(|index| safe[*index]).collect();
+    }
+
+    fn is_allowed_step(&self, from: i32, to: i32, trend: Trend) -> bool {
+        let (from, to) = (from as i64, to as i64);
+        let step = match trend {
+            Trend::Increasing => to - from,
+            Trend::Decreasing => from - to,
+            Trend::Monotonic | Trend::Any => (to - from).abs(),
+        };
+
+        return (self.min_step as i64..=self.max_step as i64).contains(&step);
+    }
+
+    /// `kept_last[index]` is the fewest removals before `index` when reading
+    /// `index` is kept. The reading kept before it is at most `removals + 1`
+    /// back, anything further would already remove too many, so this takes
+    /// `O(n * (removals + 1))`.
+    fn fewest_removals_with(&self, readings: &[i32], trend: Trend) -> usize {
+        let mut kept_last: Vec<usize> = Vec::with_capacity(readings.len());
+        let mut fewest = readings.len();
+
+        for index in 0..readings.len() {
+            // Keeping it as the first reading removes everything before it.
+            let mut removals = index;
+            let earliest = index.saturating_sub(self.removals + 1);
+            for previous in earliest..index {
+                if self.is_allowed_step(readings[previous], readings[index], trend) {
+                    removals = removals.min(kept_last[previous] + (index - previous - 1));
+                }
+            }
+            kept_last.push(removals);
+
+            // Keeping it as the last reading removes everything after it.
+            fewest = fewest.min(removals + (readings.len() - index - 1));
+        }
+
+        return fewest;
+    }
+}
+
+/// A safe report that differs from the original in `changes` readings.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Repair {
+    pub changes: usize,
+    pub repaired: Vec<i32>,
+}
+
+/// Sorted, disjoint, inclusive ranges of values.
+type Ranges = Vec<(i64, i64)>;
+
+/// Sorts `ranges`, cuts them to `bounds` and merges the ones that touch.
+fn normalize(mut ranges: Ranges, (lowest, highest): (i64, i64)) -> Ranges {
+    ranges.sort();
+    let mut merged: Ranges = vec![];
+    for (low, high) in ranges {
+        let (low, high) = (low.max(lowest), high.min(highest));
+        if low > high {
+            continue;
+        }
+        match merged.last_mut() {
+            Some(last) if low <= last.1.saturating_add(1) => last.1 = last.1.max(high),
+            _ => merged.push((low, high)),
+        }
+    }
+
+    return merged;
+}
+
+/// Every value of `ranges` plus every step of `steps`.
+fn shifted(ranges: &Ranges, steps: &Ranges) -> Ranges {
+    let sums = ranges
+        .iter()
+        .flat_map(|(low, high)| {
+            return steps
+                .iter()
+                .map(move |(step_low, step_high)| (low + step_low, high + step_high));
+        })
+        .collect();
+
+    return normalize(sums, (i64::MIN, i64::MAX));
+}
+
+fn intersect(ranges: &Ranges, others: &Ranges) -> Ranges {
+    let overlaps = ranges
+        .iter()
+        .flat_map(|(low, high)| {
+            return others
+                .iter()
+                .map(move |(other_low, other_high)| (*low.max(other_low), *high.min(other_high)));
+        })
+        .collect();
+
+    return normalize(overlaps, (i64::MIN, i64::MAX));
+}
+
+fn contains(ranges: &Ranges, value: i64) -> bool {
+    return ranges
+        .iter()
+        .any(|(low, high)| (*low..=*high).contains(&value));
+}
+
+/// The value in `ranges` nearest to `wanted`, which must not be empty.
+fn closest(ranges: &Ranges, wanted: i64) -> i64 {
+    return ranges
+        .iter()
+        .map(|(low, high)| wanted.clamp(*low, *high))
+        .min_by_key(|value| value.abs_diff(wanted))
+        .unwrap();
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Rule {
+    StepTooBig,
+    StepTooSmall,
+    ZeroStep,
+    /// Going against the report's, or the policy's, direction.
+    DirectionFlip,
+}
+
+impl fmt::Display for Rule {
+    fn fmt(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        let text = match self {
+            Rule::StepTooBig => "step too big",
+            Rule::StepTooSmall => "step too small",
+            Rule::ZeroStep => "zero step",
+            Rule::DirectionFlip => "direction flip",
+        };
+        return write!(formatter, "{text}");
+    }
+}
+
+/// The step from reading `index - 1` to reading `index` breaks `rule`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct RuleViolation {
+    pub index: usize,
+    pub rule: Rule,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Diagnosis {
+    /// Whether the report is safe as it is.
+    pub safe: bool,
+    /// `None` when the report is safe as it is.
+    pub first_violation: Option<RuleViolation>,
+    /// Indices of the readings whose removal alone makes the report safe.
+    pub safe_removals: Vec<usize>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ReportDiagnosis {
+    pub line: usize,
+    pub readings: Vec<i32>,
+    pub diagnosis: Diagnosis,
+}
+
+pub fn diagnose_reports(
+    text: &str,
+    policy: &SafetyPolicy,
+) -> Result<Vec<ReportDiagnosis>, ParseError> {
+    let mut diagnoses = vec![];
+    for report in parse::lines(text) {
+        let readings = report.whitespace_separated::<i32>()?;
+        diagnoses.push(ReportDiagnosis {
+            line: report.number,
+            diagnosis: policy.diagnose(&readings),
+            readings,
+        });
+    }
+
+    return Ok(diagnoses);
+}
+
+/// One line per report, readings counted from 1, then how many are safe as
+/// they are and with one reading removed.
+pub fn format_diagnoses(diagnoses: &[ReportDiagnosis]) -> String {
+    let mut report = String::new();
+    let mut safe = 0;
+    let mut safe_with_one_removal = 0;
+
+    for ReportDiagnosis {
+        line,
+        readings,
+        diagnosis,
+    } in diagnoses
+    {
+        let readings_text = readings
+            .iter()
+            .map(|reading| reading.to_string())
+            .collect::<Vec<String>>()
+            .join(" ");
+        let removals = diagnosis
+            .safe_removals
+            .iter()
+            .map(|index| (index + 1).to_string())
+            .collect::<Vec<String>>();
+
+        if diagnosis.safe {
+            safe += 1;
+            safe_with_one_removal += 1;
+            report += &format!("line {line}: {readings_text}: safe\n");
+            continue;
+        }
+
+        let fix = if removals.is_empty() {
+            "no single removal makes it safe".to_string()
+        } else {
+            safe_with_one_removal += 1;
+            format!("safe without reading {}", removals.join(" or "))
+        };
+        let problem = match diagnosis.first_violation {
+            Some(RuleViolation { index, rule }) => format!(
+                "{rule} from {} to {} (readings {} and {})",
+                readings[index - 1],
+                readings[index],
+                index,
+                index + 1
+            ),
+            None => "unsafe".to_string(),
+        };
+        report += &format!("line {line}: {readings_text}: {problem}, {fix}\n");
+    }
+
+    report += &format!(
+        "{safe} of {} reports safe, {safe_with_one_removal} with one reading removed\n",
+        diagnoses.len()
+    );
+    return report;
+}
+
+#[test]
+fn example_reports() {
+    let reports: [&[i32]; 6] = [
+        &[7, 6, 4, 2, 1],
+        &[1, 2, 7, 8, 9],
+        &[9, 7, 6, 2, 1],
+        &[1, 3, 2, 4, 5],
+        &[8, 6, 4, 4, 1],
+        &[1, 3, 6, 7, 9],
+    ];
+    let part_1 = SafetyPolicy::default();
+    let part_2 = part_1.with_removals(1);
+
+    let safe = |policy: SafetyPolicy| {
+        return reports
+            .iter()
+            .filter(|readings| policy.is_safe(readings))
+            .count();
+    };
+
+    assert_eq!((safe(part_1), safe(part_2)), (2, 4));
+    assert_eq!(part_2.fewest_removals(&[1, 3, 2, 4, 5]), Some(1));
+    assert_eq!(part_2.fewest_removals(&[1, 2, 7, 8, 9]), None);
+}
+
+#[test]
+fn short_reports_and_other_policies() {
+    let policy = SafetyPolicy::default();
+    assert!(policy.is_safe(&[]));
+    assert!(policy.is_safe(&[5]));
+    assert!(!policy.is_safe(&[5, 5]));
+
+    // Removing the first reading or two of them
+    let dampened = policy.with_removals(2);
+    assert_eq!(dampened.fewest_removals(&[9, 1, 2, 3, 4]), Some(1));
+    assert_eq!(dampened.fewest_removals(&[1, 9, 8, 2, 3, 4]), Some(2));
+
+    let zigzag = [1, 3, 2, 4, 3];
+    assert!(!policy.is_safe(&zigzag));
+    assert!(policy.with_trend(Trend::Any).is_safe(&zigzag));
+    assert!(!policy.with_trend(Trend::Decreasing).is_safe(&[1, 2, 3]));
+    assert!(policy.with_steps(0, 10).is_safe(&[1, 1, 11]));
+
+    // Steps between the extremes don't fit an i32.
+    let extremes = [i32::MIN, i32::MAX];
+    assert!(!policy.with_trend(Trend::Any).is_safe(&extremes));
+    assert_eq!(
+        policy.diagnose(&extremes).first_violation,
+        Some(RuleViolation {
+            index: 1,
+            rule: Rule::StepTooBig
+        })
+    );
+    assert!(!policy
+        .with_removals(1)
+        .is_safe(&[i32::MAX, i32::MIN, i32::MAX]));
+}
+
+#[test]
+fn example_diagnoses() {
+    let diagnoses = diagnose_reports(
+        "7 6 4 2 1\n1 2 7 8 9\n9 7 6 2 1\n1 3 2 4 5\n8 6 4 4 1\n1 3 6 7 9\n",
+        &SafetyPolicy::default(),
+    )
+    .unwrap();
+
+    let violations = diagnoses
+        .iter()
+        .map(|report| {
+            return report
+                .diagnosis
+                .first_violation
+                .map(|violation| (violation.index, violation.rule));
+        })
+        .collect::<Vec<_>>();
+    assert_eq!(
+        violations,
+        vec![
+            None,
+            Some((2, Rule::StepTooBig)),
+            Some((3, Rule::StepTooBig)),
+            Some((2, Rule::DirectionFlip)),
+            Some((3, Rule::ZeroStep)),
+            None,
+        ]
+    );
+    assert_eq!(diagnoses[3].diagnosis.safe_removals, vec![1, 2]);
+    assert_eq!(diagnoses[4].diagnosis.safe_removals, vec![2, 3]);
+    assert!(diagnoses[1].diagnosis.safe_removals.is_empty());
+
+    let report = format_diagnoses(&diagnoses);
+    assert!(report.contains(
+        "line 4: 1 3 2 4 5: direction flip from 3 to 2 (readings 2 and 3), safe without reading 2 or 3\n"
+    ));
+    assert!(report.ends_with("2 of 6 reports safe, 4 with one reading removed\n"));
+}
+
+#[test]
+fn zero_first_steps_do_not_hide_a_direction_flip() {
+    let policy = SafetyPolicy::default().with_steps(0, 3);
+    let diagnosis = policy.diagnose(&[1, 1, 2, 1]);
+
+    assert!(!diagnosis.safe);
+    assert_eq!(
+        diagnosis.first_violation,
+        Some(RuleViolation {
+            index: 3,
+            rule: Rule::DirectionFlip
+        })
+    );
+
+    let diagnoses = diagnose_reports("1 1 2 1\n1 1 2 3\n", &policy).unwrap();
+    assert!(
+        format_diagnoses(&diagnoses).ends_with("1 of 2 reports safe, 2 with one reading removed\n")
+    );
+}
+
+#[test]
+fn safe_removals_match_removing_each_reading() {
+    for policy in [
+        SafetyPolicy::default(),
+        SafetyPolicy::default().with_steps(0, 2),
+        SafetyPolicy::default().with_trend(Trend::Any),
+        SafetyPolicy::default().with_trend(Trend::Decreasing),
+        SafetyPolicy::default().with_steps(3, 1),
+    ] {
+        // Every report of up to six readings in 0..=3, two bits per reading.
+        let reports = (0..=6u32).flat_map(|length| {
+            return (0..4usize.pow(length)).map(move |code| {
+                return (0..length as usize)
+                    .map(|index| (code >> (2 * index)) as i32 & 3)
+                    .collect::<Vec<i32>>();
+            });
+        });
+        for readings in reports {
+            let expected = (0..readings.len())
+                .filter(|removed| {
+                    let mut remaining = readings.clone();
+                    remaining.remove(*removed);
+                    return policy.is_safe(&remaining);
+                })
+                .collect::<Vec<usize>>();
+
+            let diagnosis = policy.diagnose(&readings);
+            assert_eq!(
+                diagnosis.safe_removals, expected,
+                "{readings:?} under {policy:?}"
+            );
+            assert_eq!(
+                diagnosis.safe,
+                diagnosis.first_violation.is_none(),
+                "{readings:?} under {policy:?}"
+            );
+        }
+    }
+}
+
+#[test]
+fn example_repairs() {
+    let policy = SafetyPolicy::default();
+
+    let repair = policy.cheapest_repair(&[1, 2, 7, 8, 9]).unwrap();
+    assert_eq!(repair.changes, 1);
+    assert_eq!(repair.repaired, vec![1, 4, 7, 8, 9]);
+
+    assert_eq!(
+        policy.cheapest_repair(&[7, 6, 4, 2, 1]),
+        Some(Repair {
+            changes: 0,
+            repaired: vec![7, 6, 4, 2, 1]
+        })
+    );
+    assert_eq!(policy.cheapest_repair(&[8, 6, 4, 4, 1]).unwrap().changes, 1);
+    assert_eq!(policy.cheapest_repair(&[5]).unwrap().changes, 0);
+    assert_eq!(policy.with_steps(4, 3).cheapest_repair(&[1, 2]), None);
+}
+
+#[test]
+fn repairs_stay_within_i32() {
+    let increasing = SafetyPolicy::default().with_trend(Trend::Increasing);
+
+    assert_eq!(
+        increasing.cheapest_repair(&[i32::MAX, i32::MAX]),
+        Some(Repair {
+            changes: 1,
+            repaired: vec![i32::MAX - 1, i32::MAX]
+        })
+    );
+    // Keeping the first maximum would leave no room above it
+    assert_eq!(
+        increasing.cheapest_repair(&[i32::MAX, i32::MAX, i32::MAX]),
+        Some(Repair {
+            changes: 2,
+            repaired: vec![i32::MAX - 2, i32::MAX - 1, i32::MAX]
+        })
+    );
+    // Keeping the last reading would need three rising readings below it
+    assert_eq!(
+        increasing.cheapest_repair(&[9, 9, 9, i32::MIN + 2]),
+        Some(Repair {
+            changes: 3,
+            repaired: vec![7, 8, 9, 10]
+        })
+    );
+}
+
+#[test]
+fn repairs_are_as_small_as_brute_force_finds() {
+    fn reports(length: usize, values: std::ops::RangeInclusive<i32>) -> Vec<Vec<i32>> {
+        let mut reports = vec![vec![]];
+        for _ in 0..length {
+            reports = reports
+                .into_iter()
+                .flat_map(|report| {
+                    return values.clone().map(move |value| {
+                        let mut report = report.clone();
+                        report.push(value);
+                        return report;
+                    });
+                })
+                .collect();
+        }
+        return reports;
+    }
+
+    for policy in [
+        SafetyPolicy::default(),
+        SafetyPolicy::default().with_trend(Trend::Any),
+        SafetyPolicy::default()
+            .with_steps(2, 2)
+            .with_trend(Trend::Decreasing),
+    ] {
+        // Every repair of readings in 0..=5 stays within three steps of 3 each.
+        let safe = reports(4, -9..=14)
+            .into_iter()
+            .filter(|report| policy.is_safe(report))
+            .collect::<Vec<Vec<i32>>>();
+
+        for readings in reports(4, 0..=5) {
+            let repair = policy.cheapest_repair(&readings).unwrap();
+            let fewest = safe
+                .iter()
+                .map(|report| report.iter().zip(&readings).filter(|(a, b)| a != b).count())
+                .min()
+                .unwrap();
+
+            assert_eq!(repair.changes, fewest, "{readings:?} under {policy:?}");
+            assert!(
+                policy.is_safe(&repair.repaired),
+                "{repair:?} under {policy:?}"
+            );
+            assert_eq!(
+                repair
+                    .repaired
+                    .iter()
+                    .zip(&readings)
+                    .filter(|(a, b)| a != b)
+                    .count(),
+                repair.changes
+            );
+        }
+    }
+}
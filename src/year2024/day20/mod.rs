@@ -1,10 +1,12 @@
 use std::{
     collections::{HashMap, HashSet},
-    fs, usize,
+    usize,
 };
 
 use strum::{EnumIter, IntoEnumIterator};
 
+use crate::{input, parse};
+
 #[derive(PartialEq, Eq, Hash, Clone, Copy)]
 struct Coordinate {
     x: isize,
@@ -45,9 +47,11 @@ fn convert_string_to_racetrack(input: String) -> Racetrack {
     let mut start = Coordinate { x: 0, y: 0 };
     let mut end = Coordinate { x: 0, y: 0 };
 
-    for y in 0..input.lines().count() {
-        for x in 0..input.lines().nth(y).unwrap().len() {
-            let char = input.lines().nth(y).unwrap().chars().nth(x).unwrap();
+    let grid = parse::char_grid(&input).unwrap_or_else(|error| panic!("day 20 input: {error}"));
+
+    for y in 0..grid.len() {
+        for x in 0..grid[y].len() {
+            let char = grid[y][x];
 
             let x = x as isize;
             let y = y as isize;
@@ -83,7 +87,7 @@ fn convert_string_to_racetrack(input: String) -> Racetrack {
 }
 
 fn read_input() -> Racetrack {
-    let input = fs::read_to_string("src/day20/input.txt").expect("Day 20 input missing");
+    let input = input::read_input(2024, 20);
 
     return convert_string_to_racetrack(input);
 }
@@ -188,7 +192,8 @@ fn find_shortcuts(racetrack: &mut Racetrack, shortcut_length: usize) -> HashMap<
 
     for (coordinate, tile) in racetrack.tiles.clone() {
         if tile.tile != Tile::Wall {
-            let adjacent_tiles = get_next_coordinates_in_range(&racetrack.tiles, &coordinate, shortcut_length);
+            let adjacent_tiles =
+                get_next_coordinates_in_range(&racetrack.tiles, &coordinate, shortcut_length);
 
             for tile_to_compare in adjacent_tiles {
                 let delta_to_tile = coordinate.x.abs_diff(tile_to_compare.x)
@@ -223,7 +228,7 @@ pub fn count_number_of_shortcuts_saving_at_least_100(shortcuts: HashMap<usize, u
     return total;
 }
 
-pub fn day_20_part_1() {
+pub fn day_20_part_1() -> usize {
     let mut racetrack = read_input();
 
     get_distance_of_track(&mut racetrack);
@@ -236,9 +241,11 @@ pub fn day_20_part_1() {
         "There are {} shortcuts savinf 100 picoseconds or more",
         shortcuts_saving_100_or_more
     );
+
+    return shortcuts_saving_100_or_more;
 }
 
-pub fn day_20_part_2() {
+pub fn day_20_part_2() -> usize {
     let mut racetrack = read_input();
 
     get_distance_of_track(&mut racetrack);
@@ -251,6 +258,8 @@ pub fn day_20_part_2() {
         "There are {} shortcuts savinf 100 picoseconds or more",
         shortcuts_saving_100_or_more
     );
+
+    return shortcuts_saving_100_or_more;
 }
 
 #[test]
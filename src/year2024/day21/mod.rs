@@ -1,7 +1,6 @@
-use std::{
-    collections::{HashMap, HashSet},
-    fs,
-};
+use std::collections::{HashMap, HashSet};
+
+use crate::{context::Context, explain::markdown_table, input, parse};
 
 #[test]
 fn mix_produces_correct_response() {
@@ -41,6 +40,16 @@ fn part_1_sample() {
     assert_eq!(total, 37327623);
 }
 
+#[test]
+fn sample_part_2_best_sequence() {
+    let (best_sequence, total) = find_best_sequence(vec![1, 2, 3, 2024]);
+
+    assert_eq!(best_sequence, Sequence(-2, 1, -1, 3));
+    assert_eq!(total, 23);
+    assert_eq!(price_for_sequence(3, &best_sequence), None);
+    assert_eq!(price_for_sequence(2024, &best_sequence), Some(9));
+}
+
 #[test]
 fn get_ones_correctly() {
     let number = 8914;
@@ -52,16 +61,30 @@ fn get_ones_correctly() {
 fn sample_part_2() {
     let seeds = vec![1, 2, 3, 2024];
     let mut total = 0;
-    let selling_price = find_sequences(seeds);
+    let (_, selling_price) = find_best_sequence(seeds);
     total += selling_price;
 
     assert_eq!(total, 23)
 }
 
 fn read_input() -> Vec<i64> {
-    let text = fs::read_to_string("src/day21/input.txt").expect("day 21 input not present");
-
-    let buyers = text.lines().map(|line| line.parse().unwrap()).collect();
+    let text = input::read_input(2024, 21);
+
+    let buyers = parse::lines(&text)
+        .iter()
+        .map(|line| {
+            let [buyer] = line
+                .whitespace_separated()
+                .unwrap_or_else(|error| panic!("day 21 input: {error}"))[..]
+            else {
+                panic!(
+                    "day 21 input: {}",
+                    line.error(1, "expected one secret number")
+                );
+            };
+            return buyer;
+        })
+        .collect();
 
     return buyers;
 }
@@ -108,10 +131,13 @@ impl Sequence {
     }
 }
 
-fn find_sequences(numbers: Vec<i64>) -> u64 {
+/// The four price changes to wait for that sell for the most bananas, and how
+/// many they sell for across all buyers.
+fn find_best_sequence(numbers: Vec<i64>) -> (Sequence, u64) {
     let mut result = HashMap::new();
 
     let mut max = 0;
+    let mut best_sequence = Sequence(0, 0, 0, 0);
 
     for number in numbers {
         let mut sequence = Sequence(0, 0, 0, 0);
@@ -132,15 +158,35 @@ fn find_sequences(numbers: Vec<i64>) -> u64 {
 
                 if *total_price_for_sequence_so_far > max {
                     max = *total_price_for_sequence_so_far;
+                    best_sequence = sequence.clone();
                 }
             }
         }
     }
 
-    return max;
+    return (best_sequence, max);
 }
 
-pub fn day_21_part_1() {
+/// What one buyer pays the first time `wanted` appears in their price changes.
+fn price_for_sequence(number: i64, wanted: &Sequence) -> Option<i64> {
+    let mut sequence = Sequence(0, 0, 0, 0);
+    let mut previous_price = calculate_selling_price(number);
+    let mut number = number;
+    for index in 0..=2000 {
+        number = calculate_next_secret_number(number);
+        let price = calculate_selling_price(number);
+        sequence.move_sequence_down(price - previous_price);
+        previous_price = price;
+
+        if index > 3 && sequence == *wanted {
+            return Some(price);
+        }
+    }
+
+    return None;
+}
+
+pub fn day_21_part_1() -> i64 {
     let mut numbers = read_input();
 
     for _ in 0..2000 {
@@ -152,12 +198,34 @@ pub fn day_21_part_1() {
     let total = numbers.iter().sum::<i64>();
 
     println!("total of all buyers 2000th random number: {}", total);
+
+    return total;
 }
 
-pub fn day_21_part_2() {
+pub fn day_21_part_2(context: &Context) -> u64 {
     let numbers = read_input();
 
-    let total = find_sequences(numbers);
+    let (best_sequence, total) = find_best_sequence(numbers.clone());
+
+    context.explain("Best sequence", || {
+        let rows = numbers
+            .iter()
+            .map(|number| {
+                let price = price_for_sequence(*number, &best_sequence)
+                    .map(|price| price.to_string())
+                    .unwrap_or("never seen".to_string());
+                return vec![number.to_string(), price];
+            })
+            .collect::<Vec<Vec<String>>>();
+
+        let Sequence(first, second, third, fourth) = best_sequence;
+        return format!(
+            "Waiting for the price changes {first},{second},{third},{fourth} sells {total} bananas in total.\n\n{}",
+            markdown_table(&["initial secret", "price"], &rows)
+        );
+    });
 
     println!("Maximum price we can get: {}", total);
+
+    return total;
 }
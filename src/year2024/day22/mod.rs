@@ -1,8 +1,9 @@
 use std::{
     collections::HashMap,
-    fs,
     ops::{BitAnd, BitOr, BitXor},
 };
+
+use crate::{input, parse};
 #[derive(Debug, Clone)]
 struct Wire {
     instruction: Option<(String, Instruction, String)>,
@@ -43,40 +44,55 @@ impl BitXor for Wire {
 fn generate_gates(input: String) -> HashMap<String, Wire> {
     let mut result = HashMap::new();
 
-    for line in input.lines() {
-        if line.contains(':') {
-            let name = line[0..=2].to_string();
-            let value = Some(line.chars().last().unwrap() == '1');
-            result.insert(
-                name,
-                Wire {
-                    value,
-                    instruction: None,
-                },
-            );
-            continue;
-        }
-
-        if line.len() < 3 {
-            continue;
-        }
+    let sections = parse::sections(&input);
+    let [wire_lines, gate_lines] = &sections[..] else {
+        panic!("day 22 input should be wires and gates separated by a blank line");
+    };
+
+    for line in wire_lines {
+        let (name, value) = line
+            .key_value(":")
+            .unwrap_or_else(|error| panic!("day 22 input: {error}"));
+        let value = match value.text {
+            "0" => false,
+            "1" => true,
+            _ => panic!("day 22 input: {}", value.error(1, "expected 0 or 1")),
+        };
+        result.insert(
+            name.text.to_string(),
+            Wire {
+                value: Some(value),
+                instruction: None,
+            },
+        );
+    }
 
-        let mut parts = line.split(' ');
+    for line in gate_lines {
+        let (gate, name) = line
+            .key_value("->")
+            .unwrap_or_else(|error| panic!("day 22 input: {error}"));
+        let [value1, instruction, value2] = gate.text.split_whitespace().collect::<Vec<&str>>()[..]
+        else {
+            panic!(
+                "day 22 input: {}",
+                gate.error(1, "expected <wire> <gate> <wire>")
+            );
+        };
 
-        let value1 = parts.next().unwrap().to_string();
-        let instruction = match parts.next().unwrap() {
+        let instruction = match instruction {
             "XOR" => Instruction::Xor,
             "OR" => Instruction::Or,
             "AND" => Instruction::And,
-            _ => panic!("Invalid Instruction"),
+            _ => panic!(
+                "day 22 input: {}",
+                gate.sub(instruction).error(1, "invalid gate")
+            ),
         };
-        let value2 = parts.next().unwrap().to_string();
-        let name = parts.last().unwrap().to_string();
 
         result.insert(
-            name,
+            name.text.to_string(),
             Wire {
-                instruction: Some((value1, instruction, value2)),
+                instruction: Some((value1.to_string(), instruction, value2.to_string())),
                 value: None,
             },
         );
@@ -295,8 +311,8 @@ tnw OR pbm -> gnj"
     assert_eq!(result, 2024);
 }
 
-pub fn day_22_part_1() {
-    let input = fs::read_to_string("src/day22/input.txt").expect("day 22 input not present");
+pub fn day_22_part_1() -> usize {
+    let input = input::read_input(2024, 22);
     let mut result = generate_gates(input);
 
     resolve_values(&mut result);
@@ -304,4 +320,6 @@ pub fn day_22_part_1() {
     let result = convert_result_to_number(result);
 
     println!("The final numbe the wires spell out is: {}", result);
+
+    return result;
 }
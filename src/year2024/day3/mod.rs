@@ -1,22 +1,19 @@
-use std::fs;
-
 use regex::Regex;
 
-pub fn day_3() {
-    let input = fs::read_to_string("src/day3/input.txt").expect("File not found");
+use crate::{input, parse};
+
+pub fn day_3() -> i64 {
+    let input = input::read_input(2024, 3);
 
     let find_instructions_regex = Regex::new(r"mul\(\d{1,3},\d{1,3}\)").unwrap();
-    let find_numbers_regex = Regex::new(r"\d{1,3}").unwrap();
 
     // Find all matches
     let mut addition_result = 0;
     for multiply_instruction_match in find_instructions_regex.find_iter(&*input) {
         let mut multiply_result = 1;
-        for number_match in find_numbers_regex.find_iter(multiply_instruction_match.as_str()) {
-            let number = number_match
-                .as_str()
-                .parse::<i64>()
-                .expect("incorrect number");
+        for number in
+            parse::integers::<i64>(multiply_instruction_match.as_str()).expect("incorrect number")
+        {
             multiply_result *= number;
         }
         // println!(
@@ -29,10 +26,12 @@ pub fn day_3() {
     }
 
     println!("Total answer is: {}", addition_result);
+
+    return addition_result;
 }
 
-pub fn day_3_part_2() {
-    let mut input = fs::read_to_string("src/day3/input.txt").expect("File not found");
+pub fn day_3_part_2() -> i64 {
+    let mut input = input::read_input(2024, 3);
 
     let mut flag = true;
 
@@ -47,17 +46,14 @@ pub fn day_3_part_2() {
     }
 
     let find_instructions_regex = Regex::new(r"mul\(\d{1,3},\d{1,3}\)").unwrap();
-    let find_numbers_regex = Regex::new(r"\d{1,3}").unwrap();
 
     // Find all matches
     let mut addition_result = 0;
     for multiply_instruction_match in find_instructions_regex.find_iter(&*input) {
         let mut multiply_result = 1;
-        for number_match in find_numbers_regex.find_iter(multiply_instruction_match.as_str()) {
-            let number = number_match
-                .as_str()
-                .parse::<i64>()
-                .expect("incorrect number");
+        for number in
+            parse::integers::<i64>(multiply_instruction_match.as_str()).expect("incorrect number")
+        {
             multiply_result *= number;
         }
         // println!(
@@ -70,4 +66,6 @@ pub fn day_3_part_2() {
     }
 
     println!("Total answer is: {}", addition_result);
+
+    return addition_result;
 }
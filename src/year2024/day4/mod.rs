@@ -1,10 +1,10 @@
-use std::fs;
+use crate::input;
 
 fn day_4_input() -> String {
-    fs::read_to_string("src/day4/input.txt").expect("input file missing for day 4")
+    input::read_input(2024, 4)
 }
 
-pub fn day_4_part_1() {
+pub fn day_4_part_1() -> usize {
     let input = day_4_input();
 
     let lines: Vec<&str> = input.split("\n").collect();
@@ -53,10 +53,12 @@ pub fn day_4_part_1() {
         }
     }
 
-    println!("XMAS occurs {occurences_of_xmas} times")
+    println!("XMAS occurs {occurences_of_xmas} times");
+
+    return occurences_of_xmas;
 }
 
-pub fn day_4_part_2() {
+pub fn day_4_part_2() -> usize {
     let input = day_4_input();
 
     let lines: Vec<&str> = input.split("\n").collect();
@@ -99,6 +101,8 @@ pub fn day_4_part_2() {
         }
     }
     println!("Occurences of X-MAS : {occurences_of_xmas}");
+
+    return occurences_of_xmas;
 }
 
 fn check_for_char_at_index(lines: &Vec<&str>, char: char, index_x: usize, index_y: usize) -> bool {
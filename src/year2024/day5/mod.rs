@@ -0,0 +1,211 @@
+use crate::{context::Context, explain::markdown_table, input, parse};
+
+fn read_input() -> (Vec<[i32; 2]>, Vec<Vec<i32>>) {
+    let input = input::read_input(2024, 5);
+
+    let sections = parse::sections(&input);
+    let [rule_lines, update_lines] = &sections[..] else {
+        panic!("day 5 input should be rules and updates separated by a blank line");
+    };
+
+    let rules = rule_lines
+        .iter()
+        .map(|line| {
+            let pages = line
+                .separated::<i32>('|')
+                .unwrap_or_else(|error| panic!("day 5 input: {error}"));
+            let [before, after] = pages[..] else {
+                panic!("day 5 input: {}", line.error(1, "expected two pages"));
+            };
+            return [before, after];
+        })
+        .collect();
+
+    let updates = update_lines
+        .iter()
+        .map(|line| {
+            line.separated::<i32>(',')
+                .unwrap_or_else(|error| panic!("day 5 input: {error}"))
+        })
+        .collect();
+
+    return (rules, updates);
+}
+
+pub fn day_5_part_1(context: &Context) -> i32 {
+    let (rules, updates) = read_input();
+
+    context.explain("Broken rules per update", || {
+        let rows = updates
+            .iter()
+            .map(|update| {
+                let broken = broken_rules(&rules, update);
+                let middle_page = if broken.is_empty() {
+                    update[update.len() / 2].to_string()
+                } else {
+                    "-".to_string()
+                };
+                return vec![format_pages(update), format_rules(&broken), middle_page];
+            })
+            .collect::<Vec<Vec<String>>>();
+
+        return format!(
+            "Only updates that break no rule count towards the answer.\n\n{}",
+            markdown_table(&["update", "broken rules", "middle page"], &rows)
+        );
+    });
+
+    let mut total_of_middle_page_numbers = 0;
+    for update in updates {
+        println!("{:?}", update);
+        let mut has_broken_a_rule = false;
+        for rule in &rules {
+            if !is_following_rule(*rule, update.clone()) {
+                println!("^ is breaking rule {:?}", rule);
+                has_broken_a_rule = true;
+                break;
+            }
+        }
+        if !has_broken_a_rule {
+            println!("^ is following all rules");
+            let middle_number = update[update.len() / 2];
+            println!("{middle_number}");
+            total_of_middle_page_numbers += middle_number;
+        }
+    }
+
+    println!("{total_of_middle_page_numbers}");
+
+    return total_of_middle_page_numbers;
+}
+
+fn is_following_rule(rule: [i32; 2], update: Vec<i32>) -> bool {
+    if !update.contains(&rule[0]) || !update.contains(&rule[1]) {
+        return true;
+    }
+
+    let mut is_first_rule_first = false;
+    for page in update {
+        if page == rule[0] {
+            is_first_rule_first = true;
+            continue;
+        }
+
+        if page == rule[1] && is_first_rule_first {
+            return true;
+        }
+    }
+
+    return false;
+}
+
+pub fn day_5_part_2(context: &Context) -> i32 {
+    let (rules, updates) = read_input();
+    let mut total_of_middle_page_numbers = 0;
+    let mut reordered = vec![];
+
+    for update in updates {
+        if !is_passing_all_rules(&rules, &update) {
+            let mut fixed_update = update.clone();
+
+            for index in 0..update.len() {
+                let (_, rest) = fixed_update.split_at(index);
+                let first_number_of_list = find_first_number_in_list(&rules, rest);
+                let index_of_number_we_need = fixed_update
+                    .iter()
+                    .position(|page_number| *page_number == first_number_of_list)
+                    .unwrap();
+                fixed_update.swap(index_of_number_we_need, index);
+            }
+
+            println!(
+                "{:?} has broken rules, and has been fixed to: {:?}",
+                update, fixed_update
+            );
+
+            let middle_number = fixed_update[fixed_update.len() / 2];
+            println!("{middle_number}");
+            total_of_middle_page_numbers += middle_number;
+            reordered.push((update, fixed_update));
+        }
+    }
+    println!("{total_of_middle_page_numbers}");
+
+    context.explain("Reordered updates", || {
+        let rows = reordered
+            .iter()
+            .map(|(update, fixed_update)| {
+                vec![
+                    format_pages(update),
+                    format_rules(&broken_rules(&rules, update)),
+                    format_pages(fixed_update),
+                    fixed_update[fixed_update.len() / 2].to_string(),
+                ]
+            })
+            .collect::<Vec<Vec<String>>>();
+
+        return format!(
+            "Every update that breaks a rule, put in an order that follows them all.\n\n{}",
+            markdown_table(
+                &["update", "broken rules", "reordered", "middle page"],
+                &rows
+            )
+        );
+    });
+
+    return total_of_middle_page_numbers;
+}
+
+fn broken_rules(rules: &Vec<[i32; 2]>, update: &Vec<i32>) -> Vec<[i32; 2]> {
+    return rules
+        .iter()
+        .filter(|rule| !is_following_rule(**rule, update.clone()))
+        .copied()
+        .collect();
+}
+
+fn format_pages(pages: &Vec<i32>) -> String {
+    return pages
+        .iter()
+        .map(|page| page.to_string())
+        .collect::<Vec<String>>()
+        .join(",");
+}
+
+fn format_rules(rules: &Vec<[i32; 2]>) -> String {
+    if rules.is_empty() {
+        return "none".to_string();
+    }
+
+    return rules
+        .iter()
+        .map(|[before, after]| format!("{before}|{after}"))
+        .collect::<Vec<String>>()
+        .join(", ");
+}
+
+fn is_passing_all_rules(rules: &Vec<[i32; 2]>, update: &Vec<i32>) -> bool {
+    for rule in rules {
+        if !is_following_rule(*rule, update.clone()) {
+            return false;
+        }
+    }
+    return true;
+}
+
+fn find_first_number_in_list(rules: &Vec<[i32; 2]>, numbers: &[i32]) -> i32 {
+    for number in numbers {
+        let number_of_pages_that_should_be_in_front = rules
+            .iter()
+            .filter(|rule| rule[1] == *number)
+            .map(|rule| rule[0])
+            .filter(|page| numbers.contains(page))
+            .count();
+
+        if number_of_pages_that_should_be_in_front == 0 {
+            return *number;
+        }
+    }
+
+    return numbers[0];
+}
@@ -0,0 +1,261 @@
+use std::{
+    collections::HashSet,
+    sync::{atomic::AtomicUsize, Arc},
+    thread,
+};
+
+use crate::{
+    context::{Cancelled, Context},
+    input, parse,
+    simulation::{Driver, Ending, Simulation},
+};
+
+fn read_input() -> Vec<Vec<char>> {
+    let text = input::read_input(2024, 6);
+
+    return parse::char_grid(&text).unwrap_or_else(|error| panic!("day 6 input: {error}"));
+}
+
+pub fn day_6_part_1() -> usize {
+    let input = read_input();
+
+    let visited = visited_locations(&GuardSimulation::new(input));
+    println!("The guard took {} steps", visited.len());
+
+    return visited.len();
+}
+
+pub fn day_6_part_2(context: &Context) -> Result<usize, Cancelled> {
+    let input = read_input();
+    let floor = GuardSimulation::new(input);
+    let visited = visited_locations(&floor);
+    let total = visited.len();
+    let mut loops = 0;
+
+    for (index, location) in visited.into_iter().enumerate() {
+        context.check()?;
+        context.progress(index, total);
+
+        // The guard is standing on the start, nobody gets to put anything there.
+        if location == floor.start {
+            continue;
+        }
+        if floor.with_obstruction(location).loops() {
+            loops += 1;
+        }
+    }
+    context.finish_progress();
+
+    println!("Total options for loops: {loops}");
+
+    return Ok(loops);
+}
+
+pub fn day_6_part_2_multithread() {
+    let input = read_input();
+    let floor = GuardSimulation::new(input);
+
+    let loops = Arc::new(AtomicUsize::new(0));
+    let mut handles = vec![];
+
+    for location in visited_locations(&floor) {
+        if location == floor.start {
+            continue;
+        }
+        let test = floor.with_obstruction(location);
+        let counter = Arc::clone(&loops);
+        let handle = thread::spawn(move || {
+            if test.loops() {
+                counter.fetch_add(1, std::sync::atomic::Ordering::SeqCst);
+            }
+        });
+
+        handles.push(handle);
+    }
+
+    for handle in handles {
+        handle.join().unwrap();
+    }
+
+    println!(
+        "Total options for loops: {}",
+        loops.load(std::sync::atomic::Ordering::SeqCst)
+    );
+}
+
+/// Every location the guard stands on before leaving the floor.
+fn visited_locations(floor: &GuardSimulation) -> HashSet<(usize, usize)> {
+    let run = Driver::new(floor).with_history().run(usize::MAX);
+    if run.ending != Ending::Terminal {
+        panic!("the guard never leaves the floor");
+    }
+
+    return run
+        .history
+        .iter()
+        .filter_map(|guard| guard.position)
+        .collect();
+}
+
+#[derive(Clone, Copy, PartialEq, Hash, Eq, Debug)]
+enum MoveDirection {
+    North,
+    East,
+    South,
+    West,
+}
+
+#[derive(Clone, Copy, PartialEq, Eq, Hash, Debug)]
+struct Guard {
+    /// `None` once the guard walked off the floor.
+    position: Option<(usize, usize)>,
+    direction: MoveDirection,
+}
+
+/// The floor with its obstructions, each tick the guard either turns right or
+/// takes a step.
+#[derive(Clone)]
+struct GuardSimulation {
+    map: Vec<Vec<char>>,
+    start: (usize, usize),
+}
+
+impl GuardSimulation {
+    fn new(map: Vec<Vec<char>>) -> Self {
+        let start = map
+            .iter()
+            .enumerate()
+            .find_map(|(y, line)| {
+                return line.iter().position(|char| *char == '^').map(|x| (x, y));
+            })
+            .expect("starting position not found");
+
+        return GuardSimulation { map, start };
+    }
+
+    fn with_obstruction(&self, (x, y): (usize, usize)) -> Self {
+        let mut map = self.map.clone();
+        map[y][x] = '#';
+
+        return GuardSimulation {
+            map,
+            start: self.start,
+        };
+    }
+
+    /// Walking in circles means a guard state came back.
+    fn loops(&self) -> bool {
+        let run = Driver::new(self).with_cycle_detection().run(usize::MAX);
+
+        return matches!(run.ending, Ending::Cycle { .. });
+    }
+}
+
+impl Simulation for GuardSimulation {
+    type State = Guard;
+    type Key = Guard;
+
+    fn initial_state(&self) -> Guard {
+        return Guard {
+            position: Some(self.start),
+            direction: MoveDirection::North,
+        };
+    }
+
+    fn step(&self, guard: &mut Guard) {
+        let Some((x, y)) = guard.position else {
+            return;
+        };
+        let (x_new, y_new) = next_index_in_direction(&x, &y, &guard.direction);
+        let next = usize::try_from(y_new)
+            .ok()
+            .zip(usize::try_from(x_new).ok())
+            .and_then(|(y_new, x_new)| {
+                return self
+                    .map
+                    .get(y_new)?
+                    .get(x_new)
+                    .map(|tile| (x_new, y_new, *tile));
+            });
+
+        match next {
+            None => guard.position = None,
+            Some((_, _, '#')) => guard.direction = rotate_90_deg(&guard.direction),
+            Some((x_new, y_new, _)) => guard.position = Some((x_new, y_new)),
+        }
+    }
+
+    fn is_terminal(&self, guard: &Guard) -> bool {
+        return guard.position.is_none();
+    }
+
+    fn render(&self, guard: &Guard) -> String {
+        let mut board = self.map.clone();
+        board[self.start.1][self.start.0] = '.';
+        if let Some((x, y)) = guard.position {
+            board[y][x] = match guard.direction {
+                MoveDirection::North => '^',
+                MoveDirection::East => '>',
+                MoveDirection::South => 'v',
+                MoveDirection::West => '<',
+            };
+        }
+
+        return board
+            .iter()
+            .map(|line| line.iter().collect::<String>() + "\n")
+            .collect();
+    }
+
+    fn state_key(&self, guard: &Guard) -> Guard {
+        return *guard;
+    }
+}
+
+fn next_index_in_direction(x: &usize, y: &usize, direction: &MoveDirection) -> (isize, isize) {
+    let x = *x as isize;
+    let y = *y as isize;
+    match direction {
+        MoveDirection::North => return (x, y - 1),
+        MoveDirection::East => return ((x + 1), y),
+        MoveDirection::South => return (x, (y + 1)),
+        MoveDirection::West => return ((x - 1), y),
+    }
+}
+
+fn rotate_90_deg(direction: &MoveDirection) -> MoveDirection {
+    match direction {
+        MoveDirection::North => MoveDirection::East,
+        MoveDirection::East => MoveDirection::South,
+        MoveDirection::South => MoveDirection::West,
+        MoveDirection::West => MoveDirection::North,
+    }
+}
+
+#[test]
+fn example_route_and_loops() {
+    let map = parse::char_grid(
+        "....#.....
+.........#
+..........
+..#.......
+.......#..
+..........
+.#..^.....
+........#.
+#.........
+......#...
+",
+    )
+    .unwrap();
+    let floor = GuardSimulation::new(map);
+
+    let visited = visited_locations(&floor);
+    let loops = visited
+        .iter()
+        .filter(|location| **location != floor.start)
+        .filter(|location| floor.with_obstruction(**location).loops())
+        .count();
+
+    assert_eq!((visited.len(), loops), (41, 6));
+}
@@ -0,0 +1,163 @@
+use std::usize;
+
+use crate::{context::Context, explain::markdown_table, input, parse};
+
+fn read_input() -> Vec<CalibrationSet> {
+    let text = input::read_input(2024, 7);
+
+    return parse::lines(&text)
+        .iter()
+        .map(|line| {
+            let (answer, values) = line.key_value(":")?;
+
+            let answer = answer.whitespace_separated::<usize>()?;
+            let [answer] = answer[..] else {
+                return Err(line.error(1, "expected a single test value"));
+            };
+            let values = values.whitespace_separated::<usize>()?;
+
+            return Ok(CalibrationSet { answer, values });
+        })
+        .collect::<Result<Vec<CalibrationSet>, parse::ParseError>>()
+        .unwrap_or_else(|error| panic!("day 7 input: {error}"));
+}
+
+struct CalibrationSet {
+    answer: usize,
+    values: Vec<usize>,
+}
+
+pub fn day_7_part_1(context: &Context) -> usize {
+    return total_calibration(context, 2);
+}
+
+pub fn day_7_part_2(context: &Context) -> usize {
+    return total_calibration(context, 3);
+}
+
+/// The sum of the test values that some choice of the first `base` operators
+/// makes true, explaining which choice was found for each.
+fn total_calibration(context: &Context, base: usize) -> usize {
+    let input = read_input();
+
+    let mut correct_calibrations = vec![];
+    let mut equations = vec![];
+    for set in input {
+        if let Some(operators) = find_operators(&set.values, &set.answer, base) {
+            correct_calibrations.push(set.answer);
+            equations.push(vec![
+                set.answer.to_string(),
+                format_equation(&set.values, &operators),
+            ]);
+        }
+    }
+    context.explain("Operators found", || {
+        return format!(
+            "The first assignment of operators that makes each solvable equation true, evaluated left to right.\n\n{}",
+            markdown_table(&["test value", "equation"], &equations)
+        );
+    });
+
+    let total = correct_calibrations.iter().sum::<usize>();
+
+    println!(
+        "There are {} correct calibrations, with a total of {:?}",
+        correct_calibrations.len(),
+        total
+    );
+
+    return total;
+}
+
+/// Operators by the digit that picks them when counting through assignments in
+/// `base`: 0 multiplies, 1 adds and 2 concatenates.
+const OPERATORS: [&str; 3] = ["*", "+", "||"];
+
+/// The operators between `values` that produce `answer`, if there are any.
+fn find_operators(values: &Vec<usize>, answer: &usize, base: usize) -> Option<Vec<&'static str>> {
+    let mut operators = None;
+
+    let mut binary_number: usize = base.pow(values.len() as u32 - 1) - 1;
+
+    let padding = to_base_n(binary_number, base).len();
+    loop {
+        let mut total = values[0];
+        for index in 0..values.len() - 1 {
+            match format!("{:0>1$}", to_base_n(binary_number, base), padding)
+                .chars()
+                .nth(index)
+            {
+                Some('2') => {
+                    total = format!("{}{}", total, values[index + 1])
+                        .parse()
+                        .expect("Error with input")
+                }
+                Some('1') => total += values[index + 1],
+                Some('0') => total *= values[index + 1],
+                Some(_) => continue,
+                None => continue,
+            }
+            if total > *answer {
+                break;
+            }
+        }
+        if total == *answer {
+            operators = Some(
+                format!("{:0>1$}", to_base_n(binary_number, base), padding)
+                    .chars()
+                    .take(values.len() - 1)
+                    .map(|digit| OPERATORS[digit.to_digit(10).unwrap() as usize])
+                    .collect(),
+            );
+            break;
+        }
+        if binary_number == 0 {
+            break;
+        }
+        binary_number -= 1;
+    }
+
+    return operators;
+}
+
+fn format_equation(values: &Vec<usize>, operators: &Vec<&str>) -> String {
+    let mut equation = values[0].to_string();
+    for (operator, value) in operators.iter().zip(&values[1..]) {
+        equation += &format!(" {operator} {value}");
+    }
+
+    return equation;
+}
+
+fn to_base_n(mut num: usize, base: usize) -> String {
+    if num == 0 {
+        return "0".to_string();
+    }
+
+    let mut digits = Vec::new();
+    while num > 0 {
+        digits.push((num % base).to_string());
+        num /= base;
+    }
+
+    digits.reverse();
+    digits.concat()
+}
+
+#[test]
+fn finds_operators_for_example_equations() {
+    let operators = find_operators(&vec![81, 40, 27], &3267, 2).unwrap();
+    assert_eq!(
+        format_equation(&vec![81, 40, 27], &operators),
+        "81 + 40 * 27"
+    );
+
+    let operators = find_operators(&vec![6, 8, 6, 15], &7290, 3).unwrap();
+    assert_eq!(operators, vec!["*", "||", "*"]);
+    assert_eq!(find_operators(&vec![17, 5], &83, 3), None);
+}
+
+#[test]
+fn examples_solve() {
+    crate::examples::assert_examples_solve(2024, 7);
+}
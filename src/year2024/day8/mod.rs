@@ -0,0 +1,221 @@
+use std::collections::{HashMap, HashSet};
+
+use crate::{input, number_theory::gcd, parse};
+
+fn read_input() -> Vec<Vec<char>> {
+    return parse_map(&input::read_input(2024, 8));
+}
+
+fn parse_map(text: &str) -> Vec<Vec<char>> {
+    return parse::char_grid(text).unwrap_or_else(|error| panic!("day 8 input: {error}"));
+}
+
+pub fn day_8_part_1() -> usize {
+    let input = read_input();
+
+    let antennas = get_antenna_groupings(&input);
+
+    let antinodes = calculate_antinode_coordinates(antennas, input.len(), input[1].len(), true);
+
+    // println!("{}", draw_antinodes(&input, &antinodes));
+
+    println!("Total of {} antinodes", antinodes.len());
+
+    return antinodes.len();
+}
+
+pub fn day_8_part_2() -> usize {
+    let input = read_input();
+
+    let antennas = get_antenna_groupings(&input);
+
+    let antinodes = calculate_antinode_coordinates(antennas, input.len(), input[1].len(), false);
+
+    println!("{}", draw_antinodes(&input, &antinodes));
+
+    println!("Total of {} antinodes", antinodes.len());
+
+    return antinodes.len();
+}
+
+#[derive(Clone, Copy, PartialEq, Hash, Eq, Debug)]
+struct Coordinate {
+    x: usize,
+    y: usize,
+}
+
+fn get_antenna_groupings(input: &Vec<Vec<char>>) -> HashMap<char, Vec<Coordinate>> {
+    let mut antennas = HashMap::<char, Vec<Coordinate>>::new();
+
+    for y in 0..input.len() {
+        for x in 0..input[y].len() {
+            let char = input[y][x];
+
+            if char != '.' {
+                antennas
+                    .entry(char)
+                    .or_insert(vec![])
+                    .push(Coordinate { x, y });
+            }
+        }
+    }
+
+    return antennas;
+}
+
+fn calculate_antinode_coordinates(
+    antenna_grouping: HashMap<char, Vec<Coordinate>>,
+    x_max: usize,
+    y_max: usize,
+    limit_to_first: bool,
+) -> HashSet<Coordinate> {
+    let mut antinode_coordinates = HashSet::<Coordinate>::new();
+
+    for (_, coordinates) in antenna_grouping {
+        let mut new_coordinates = coordinates.clone();
+        for _ in 0..coordinates.len() {
+            let current_coordinate = new_coordinates.remove(0);
+
+            let remaining_coordinates = new_coordinates.clone();
+
+            for comparison_coordinate in remaining_coordinates {
+                let antinodes = calculate_antinodes(
+                    &current_coordinate,
+                    &comparison_coordinate,
+                    x_max,
+                    y_max,
+                    limit_to_first,
+                );
+
+                for antinode in antinodes {
+                    antinode_coordinates.insert(antinode);
+                }
+            }
+        }
+    }
+
+    return antinode_coordinates;
+}
+
+fn calculate_antinodes(
+    coordinate: &Coordinate,
+    comparison_coordinate: &Coordinate,
+    x_max: usize,
+    y_max: usize,
+    limit_to_first: bool,
+) -> Vec<Coordinate> {
+    let delta_x = comparison_coordinate.x as i32 - coordinate.x as i32;
+    let delta_y = comparison_coordinate.y as i32 - coordinate.y as i32;
+    let is_on_map = |x: i32, y: i32| x >= 0 && x < x_max as i32 && y >= 0 && y < y_max as i32;
+
+    if limit_to_first {
+        return [
+            (coordinate.x as i32 - delta_x, coordinate.y as i32 - delta_y),
+            (
+                comparison_coordinate.x as i32 + delta_x,
+                comparison_coordinate.y as i32 + delta_y,
+            ),
+        ]
+        .into_iter()
+        .filter(|(x, y)| is_on_map(*x, *y))
+        .map(|(x, y)| Coordinate {
+            x: x as usize,
+            y: y as usize,
+        })
+        .collect();
+    }
+
+    // Resonant antinodes are every grid point on the line, including the ones
+    // between the antennas, so step by the smallest whole delta along it.
+    let divisor = gcd(delta_x as i128, delta_y as i128) as i32;
+    let (step_x, step_y) = (delta_x / divisor, delta_y / divisor);
+
+    let mut coordinates = vec![];
+    for direction in [1, -1] {
+        let mut x = coordinate.x as i32;
+        let mut y = coordinate.y as i32;
+        if direction == -1 {
+            x -= step_x;
+            y -= step_y;
+        }
+
+        while is_on_map(x, y) {
+            coordinates.push(Coordinate {
+                x: x as usize,
+                y: y as usize,
+            });
+
+            x += direction * step_x;
+            y += direction * step_y;
+        }
+    }
+
+    return coordinates;
+}
+
+fn draw_antinodes(input: &Vec<Vec<char>>, antinodes: &HashSet<Coordinate>) -> String {
+    let mut input = input.clone();
+    for coordinate in antinodes {
+        input[coordinate.y][coordinate.x] = '#'
+    }
+
+    return render_board(&input);
+}
+
+fn render_board(input: &Vec<Vec<char>>) -> String {
+    let mut final_string = "".to_string();
+    for column in input {
+        for character in column {
+            final_string += &character.to_string();
+        }
+        final_string += "\n";
+    }
+
+    return final_string;
+}
+
+#[test]
+fn example_antinodes_snapshot() {
+    let input = parse_map(&input::read_day_file(2024, 8, "example.txt"));
+    let antennas = get_antenna_groupings(&input);
+
+    let antinodes =
+        calculate_antinode_coordinates(antennas.clone(), input.len(), input[1].len(), true);
+    let resonant_antinodes =
+        calculate_antinode_coordinates(antennas, input.len(), input[1].len(), false);
+
+    assert_eq!(antinodes.len(), 14);
+    assert_eq!(resonant_antinodes.len(), 34);
+    crate::snapshot::assert_snapshot(
+        2024,
+        8,
+        "example_antinodes",
+        &draw_antinodes(&input, &antinodes),
+    );
+    crate::snapshot::assert_snapshot(
+        2024,
+        8,
+        "example_resonant_antinodes",
+        &draw_antinodes(&input, &resonant_antinodes),
+    );
+}
+
+#[test]
+fn resonant_antinodes_include_points_between_antennas() {
+    let antinodes = calculate_antinodes(
+        &Coordinate { x: 0, y: 0 },
+        &Coordinate { x: 2, y: 4 },
+        5,
+        5,
+        false,
+    );
+
+    assert_eq!(
+        antinodes,
+        vec![
+            Coordinate { x: 0, y: 0 },
+            Coordinate { x: 1, y: 2 },
+            Coordinate { x: 2, y: 4 },
+        ]
+    );
+}
@@ -1,7 +1,9 @@
-use std::{fs, usize};
+use std::usize;
+
+use crate::input;
 
 fn read_input() -> Vec<String> {
-    let input_text = fs::read_to_string("src/day9/input.txt").expect("Error reading input");
+    let input_text = input::read_input(2024, 9);
 
     let mut files = vec![];
     let mut empty_space = vec![];
@@ -29,18 +31,22 @@ fn read_input() -> Vec<String> {
     return parsed_string;
 }
 
-pub fn day_9_part_1() {
+pub fn day_9_part_1() -> usize {
     let mut input = read_input();
     compact_contents(&mut input);
     let checksum = calculate_checksum(input);
-    println!("{checksum}")
+    println!("{checksum}");
+
+    return checksum;
 }
 
-pub fn day_9_part_2() {
+pub fn day_9_part_2() -> usize {
     let mut input = read_input();
     compact_files(&mut input);
     let checksum = calculate_checksum(input);
-    println!("{checksum}")
+    println!("{checksum}");
+
+    return checksum;
 }
 
 fn compact_contents(input: &mut Vec<String>) {
@@ -0,0 +1,240 @@
+pub mod day1;
+pub mod day10;
+pub mod day11;
+pub mod day12;
+pub mod day13;
+pub mod day14;
+pub mod day15;
+pub mod day16;
+pub mod day17;
+pub mod day18;
+pub mod day19;
+pub mod day2;
+pub mod day20;
+pub mod day21;
+pub mod day22;
+pub mod day3;
+pub mod day4;
+pub mod day5;
+pub mod day6;
+pub mod day7;
+pub mod day8;
+pub mod day9;
+
+use crate::registry::{PuzzleInfo, Solution};
+
+use self::{
+    day1::{day_1_part_1, day_1_part_2},
+    day10::{day_10_part_1, day_10_part_2},
+    day11::{day_11_part_1, day_11_part_2},
+    day12::{day_12_part_1, day_12_part_2},
+    day13::{day_13_part_1, day_13_part_2},
+    day14::{day_14_part_1, day_14_part_2},
+    day15::{day_15_part_1, day_15_part_2},
+    day16::day_16_part_1,
+    day17::{day_17_part_1, day_17_part_2},
+    day18::{day_18_part_1, day_18_part_2},
+    day19::{day_19_part_1, day_19_part_2},
+    day2::day_2,
+    day20::{day_20_part_1, day_20_part_2},
+    day21::{day_21_part_1, day_21_part_2},
+    day22::day_22_part_1,
+    day3::{day_3, day_3_part_2},
+    day4::{day_4_part_1, day_4_part_2},
+    day5::{day_5_part_1, day_5_part_2},
+    day6::{day_6_part_1, day_6_part_2},
+    day7::{day_7_part_1, day_7_part_2},
+    day8::{day_8_part_1, day_8_part_2},
+    day9::{day_9_part_1, day_9_part_2},
+};
+
+pub const YEAR: usize = 2024;
+
+pub fn solutions() -> Vec<Solution> {
+    return vec![
+        Solution::new(YEAR, 1, 1, |context| Ok(day_1_part_1(context).to_string())),
+        Solution::new(YEAR, 1, 2, |_| Ok(day_1_part_2().to_string())),
+        Solution::new(YEAR, 2, 1, |_| Ok(day_2().0.to_string())),
+        Solution::new(YEAR, 2, 2, |_| Ok(day_2().1.to_string())),
+        Solution::new(YEAR, 3, 1, |_| Ok(day_3().to_string())),
+        Solution::new(YEAR, 3, 2, |_| Ok(day_3_part_2().to_string())),
+        Solution::new(YEAR, 4, 1, |_| Ok(day_4_part_1().to_string())),
+        Solution::new(YEAR, 4, 2, |_| Ok(day_4_part_2().to_string())),
+        Solution::new(YEAR, 5, 1, |context| Ok(day_5_part_1(context).to_string())),
+        Solution::new(YEAR, 5, 2, |context| Ok(day_5_part_2(context).to_string())),
+        Solution::new(YEAR, 6, 1, |_| Ok(day_6_part_1().to_string())),
+        Solution::new(YEAR, 6, 2, |context| {
+            day_6_part_2(context).map(|answer| answer.to_string())
+        }),
+        Solution::new(YEAR, 7, 1, |context| Ok(day_7_part_1(context).to_string())),
+        Solution::new(YEAR, 7, 2, |context| Ok(day_7_part_2(context).to_string())),
+        Solution::new(YEAR, 8, 1, |_| Ok(day_8_part_1().to_string())),
+        Solution::new(YEAR, 8, 2, |_| Ok(day_8_part_2().to_string())),
+        Solution::new(YEAR, 9, 1, |_| Ok(day_9_part_1().to_string())),
+        Solution::new(YEAR, 9, 2, |_| Ok(day_9_part_2().to_string())),
+        Solution::new(YEAR, 10, 1, |_| Ok(day_10_part_1().to_string())),
+        Solution::new(YEAR, 10, 2, |_| Ok(day_10_part_2().to_string())),
+        Solution::new(YEAR, 11, 1, |_| Ok(day_11_part_1().to_string())),
+        Solution::new(YEAR, 11, 2, |_| Ok(day_11_part_2().to_string())),
+        Solution::new(
+            YEAR,
+            12,
+            1,
+            |context| Ok(day_12_part_1(context).to_string()),
+        ),
+        Solution::new(
+            YEAR,
+            12,
+            2,
+            |context| Ok(day_12_part_2(context).to_string()),
+        ),
+        Solution::new(YEAR, 13, 1, |_| Ok(day_13_part_1().to_string())),
+        Solution::new(YEAR, 13, 2, |_| Ok(day_13_part_2().to_string())),
+        Solution::new(YEAR, 14, 1, |_| Ok(day_14_part_1().to_string())),
+        Solution::new(YEAR, 14, 2, |context| {
+            day_14_part_2(context).map(|answer| answer.to_string())
+        }),
+        Solution::new(YEAR, 15, 1, |_| Ok(day_15_part_1().to_string())),
+        Solution::new(YEAR, 15, 2, |_| Ok(day_15_part_2().to_string())),
+        Solution::new(YEAR, 16, 1, |_| Ok(day_16_part_1().to_string())),
+        Solution::new(YEAR, 17, 1, |_| Ok(day_17_part_1().to_string())),
+        Solution::new(YEAR, 17, 2, |_| Ok(day_17_part_2().to_string())),
+        Solution::new(YEAR, 18, 1, |_| Ok(day_18_part_1().to_string())),
+        Solution::new(YEAR, 18, 2, day_18_part_2),
+        Solution::new(YEAR, 19, 1, |_| Ok(day_19_part_1().to_string())),
+        Solution::new(YEAR, 19, 2, |_| Ok(day_19_part_2().to_string())),
+        Solution::new(YEAR, 20, 1, |_| Ok(day_20_part_1().to_string())),
+        Solution::new(YEAR, 20, 2, |_| Ok(day_20_part_2().to_string())),
+        Solution::new(YEAR, 21, 1, |_| Ok(day_21_part_1().to_string())),
+        Solution::new(
+            YEAR,
+            21,
+            2,
+            |context| Ok(day_21_part_2(context).to_string()),
+        ),
+        Solution::new(YEAR, 22, 1, |_| Ok(day_22_part_1().to_string())),
+    ];
+}
+
+/// Module numbers follow the order the puzzles were solved in, which drifts
+/// from the calendar after day 20.
+pub const PUZZLES: [PuzzleInfo; 22] = [
+    PuzzleInfo::new(YEAR, 1, 1, "Historian Hysteria", "two columns of integers"),
+    PuzzleInfo::new(
+        YEAR,
+        2,
+        2,
+        "Red-Nosed Reports",
+        "rows of space separated integers",
+    ),
+    PuzzleInfo::new(
+        YEAR,
+        3,
+        3,
+        "Mull It Over",
+        "corrupted text containing mul(a,b)",
+    ),
+    PuzzleInfo::new(YEAR, 4, 4, "Ceres Search", "grid of X, M, A and S"),
+    PuzzleInfo::new(
+        YEAR,
+        5,
+        5,
+        "Print Queue",
+        "a|b rules, blank line, comma separated updates",
+    ),
+    PuzzleInfo::new(
+        YEAR,
+        6,
+        6,
+        "Guard Gallivant",
+        "square grid of . and # with one ^",
+    ),
+    PuzzleInfo::new(
+        YEAR,
+        7,
+        7,
+        "Bridge Repair",
+        "answer: space separated integers",
+    ),
+    PuzzleInfo::new(
+        YEAR,
+        8,
+        8,
+        "Resonant Collinearity",
+        "grid of . and antenna characters",
+    ),
+    PuzzleInfo::new(YEAR, 9, 9, "Disk Fragmenter", "one line of digits"),
+    PuzzleInfo::new(YEAR, 10, 10, "Hoof It", "grid of digits 0 to 9"),
+    PuzzleInfo::new(
+        YEAR,
+        11,
+        11,
+        "Plutonian Pebbles",
+        "one line of space separated integers",
+    ),
+    PuzzleInfo::new(YEAR, 12, 12, "Garden Groups", "grid of plant letters"),
+    PuzzleInfo::new(
+        YEAR,
+        13,
+        13,
+        "Claw Contraption",
+        "blocks of button A, button B and prize lines",
+    ),
+    PuzzleInfo::new(
+        YEAR,
+        14,
+        14,
+        "Restroom Redoubt",
+        "p=x,y v=dx,dy lines plus size.txt",
+    ),
+    PuzzleInfo::new(
+        YEAR,
+        15,
+        15,
+        "Warehouse Woes",
+        "grid of # . O @, blank line, moves",
+    ),
+    PuzzleInfo::new(
+        YEAR,
+        16,
+        16,
+        "Reindeer Maze",
+        "grid of # and . with one S and one E",
+    ),
+    PuzzleInfo::new(
+        YEAR,
+        17,
+        17,
+        "Chronospatial Computer",
+        "three registers, blank line, program",
+    ),
+    PuzzleInfo::new(YEAR, 18, 18, "RAM Run", "x,y coordinate lines"),
+    PuzzleInfo::new(
+        YEAR,
+        19,
+        19,
+        "Linen Layout",
+        "comma separated towels, blank line, designs",
+    ),
+    PuzzleInfo::new(
+        YEAR,
+        20,
+        20,
+        "Race Condition",
+        "grid of # and . with one S and one E",
+    ),
+    PuzzleInfo::new(
+        YEAR,
+        21,
+        22,
+        "Monkey Market",
+        "one initial secret number per line",
+    ),
+    PuzzleInfo::new(
+        YEAR,
+        22,
+        24,
+        "Crossed Wires",
+        "wire: bit lines, blank line, gate lines",
+    ),
+];